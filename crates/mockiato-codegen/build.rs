@@ -1,7 +1,14 @@
 use rustc_version::{version_meta, Channel};
 
 fn main() {
-    if let Channel::Nightly = version_meta().unwrap().channel {
+    let version_meta = version_meta().unwrap();
+
+    if let Channel::Nightly = version_meta.channel {
         println!("cargo:rustc-cfg=rustc_is_nightly");
     }
+
+    // `#[track_caller]` and `Location::caller()` are stable since rust 1.46
+    if (version_meta.semver.major, version_meta.semver.minor) >= (1, 46) {
+        println!("cargo:rustc-cfg=rustc_has_track_caller");
+    }
 }
@@ -1,4 +1,20 @@
 pub(crate) const ATTR_NAME: &str = "mockable";
 pub(crate) const STATIC_REFERENCES_ATTR_PARAM_NAME: &str = "static_references";
 pub(crate) const MOCK_STRUCT_NAME_ATTR_PARAM_NAME: &str = "name";
+pub(crate) const DEFAULT_RETURNS_ATTR_PARAM_NAME: &str = "default_returns";
+pub(crate) const NICE_ATTR_PARAM_NAME: &str = "nice";
+pub(crate) const SYNC_ATTR_PARAM_NAME: &str = "sync";
+/// The members generated on every mock struct, which would collide with trait methods of the same name.
+pub(crate) const MOCK_STRUCT_MEMBER_NAMES: &[&str] = &[
+    "phantom_data",
+    "disarmed",
+    "set_ambiguity_resolution",
+    "set_strictness",
+    "unexpected_calls",
+    "recorded_calls",
+    "try_verify",
+    "disarm",
+    "checkpoint",
+    "phase",
+];
 pub(crate) const CREATE_ISSUE_LINK: &str = "https://github.com/myelin-ai/mockiato/issues/new";
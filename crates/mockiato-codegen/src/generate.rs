@@ -3,16 +3,24 @@ use self::arguments_matcher::generate_arguments_matcher;
 use self::constant::{arguments_ident, arguments_matcher_ident};
 use self::constant::{mock_lifetime, mock_lifetime_as_generic_param, mock_struct_ident, mod_ident};
 use self::drop_impl::generate_drop_impl;
-use self::generics::get_matching_generics_for_method_inputs;
+use self::generics::{
+    generics_for_method_decl, generics_with_associated_types,
+    get_matching_generics_for_method_inputs,
+};
 use self::mock_struct::generate_mock_struct;
 use self::trait_impl::generate_trait_impl;
 use self::visibility::raise_visibility_by_one_level;
+use crate::parse::const_attr::ConstAttr;
 use crate::parse::method_decl::MethodDecl;
+use crate::parse::return_kind::ReturnKind;
 use crate::parse::trait_decl::TraitDecl;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{parse_quote, Generics, Ident, ReturnType, Type, WherePredicate};
 
+pub(crate) use self::constant::generated_method_idents;
+
+mod argument_capture;
 pub(crate) mod arguments;
 pub(crate) mod arguments_matcher;
 mod bound_lifetimes;
@@ -31,6 +39,10 @@ mod visibility;
 pub(crate) struct GenerateMockOptions {
     pub(crate) custom_struct_ident: Option<Ident>,
     pub(crate) force_static_lifetimes: bool,
+    pub(crate) default_returns: bool,
+    pub(crate) nice: bool,
+    pub(crate) sync: bool,
+    pub(crate) associated_const_values: Vec<ConstAttr>,
 }
 
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
@@ -39,6 +51,10 @@ pub(crate) struct GenerateMockParameters {
     pub(crate) mod_ident: Ident,
     pub(crate) generics: Generics,
     pub(crate) methods: Vec<MethodDeclMetadata>,
+    pub(crate) default_returns: bool,
+    pub(crate) nice: bool,
+    pub(crate) sync: bool,
+    pub(crate) associated_const_values: Vec<ConstAttr>,
 }
 
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
@@ -73,6 +89,10 @@ pub(crate) fn generate_mock(trait_decl: &TraitDecl, options: GenerateMockOptions
         mod_ident: mod_ident(&mock_struct_ident),
         generics: generics_for_trait_decl(trait_decl, static_lifetime_restriction),
         methods,
+        default_returns: options.default_returns,
+        nice: options.nice,
+        sync: options.sync,
+        associated_const_values: options.associated_const_values,
     };
 
     let mock_struct = generate_mock_struct(trait_decl, &parameters);
@@ -82,7 +102,7 @@ pub(crate) fn generate_mock(trait_decl: &TraitDecl, options: GenerateMockOptions
     let arguments: TokenStream = parameters
         .methods
         .iter()
-        .map(|method| generate_argument_structs(method, trait_decl))
+        .map(|method| generate_argument_structs(method, trait_decl, parameters.sync))
         .collect();
 
     let drop_impl = generate_drop_impl(trait_decl, &parameters);
@@ -109,8 +129,10 @@ fn map_method_decl_to_method_decl_metadata(
     method_decl: MethodDecl,
     trait_decl: &TraitDecl,
 ) -> MethodDeclMetadata {
-    let generics =
-        get_matching_generics_for_method_inputs(&method_decl.inputs, &trait_decl.generics);
+    let generics = get_matching_generics_for_method_inputs(
+        &method_decl.inputs,
+        &generics_for_method_decl(&method_decl, &generics_with_associated_types(trait_decl)),
+    );
     let arguments_struct_ident = arguments_ident(&method_decl.ident);
     let arguments_matcher_struct_ident = arguments_matcher_ident(&method_decl.ident);
     let return_type = return_type(&method_decl);
@@ -128,7 +150,7 @@ fn generics_for_trait_decl(
     trait_decl: &TraitDecl,
     static_lifetime_restriction: Option<WherePredicate>,
 ) -> Generics {
-    let mut generics = trait_decl.generics.clone();
+    let mut generics = generics_with_associated_types(trait_decl);
     generics.params.push(mock_lifetime_as_generic_param());
 
     if let Some(static_lifetime_restriction) = static_lifetime_restriction {
@@ -149,10 +171,11 @@ fn get_static_lifetime_restriction() -> WherePredicate {
 fn generate_argument_structs(
     method: &MethodDeclMetadata,
     trait_decl: &TraitDecl,
+    sync: bool,
 ) -> proc_macro2::TokenStream {
     let visibility = raise_visibility_by_one_level(&trait_decl.visibility);
     let arguments = generate_arguments(method, &visibility);
-    let arguments_matcher = generate_arguments_matcher(method, &visibility);
+    let arguments_matcher = generate_arguments_matcher(method, &visibility, sync);
 
     quote! {
         #arguments
@@ -161,6 +184,12 @@ fn generate_argument_structs(
 }
 
 fn return_type(method_decl: &MethodDecl) -> Type {
+    if let ReturnKind::ImplFuture(output) | ReturnKind::BoxedFuture(output) =
+        &method_decl.return_kind
+    {
+        return output.clone();
+    }
+
     match &method_decl.output {
         ReturnType::Default => parse_quote! { () },
         ReturnType::Type(_, ty) => ty.as_ref().clone(),
@@ -0,0 +1,135 @@
+use super::MethodDeclMetadata;
+use crate::parse::method_inputs::MethodArg;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::visit::{visit_path, visit_type, Visit};
+use syn::{Ident, Lifetime, Path, Type, TypeReference};
+
+/// Describes how an argument is copied so that calls can be verified after they were made.
+pub(super) enum ArgumentCapture<'a> {
+    /// The argument is a shared reference to a type implementing `ToOwned`.
+    Borrowed(&'a Type),
+    /// The argument is owned and implements `Clone`.
+    Owned(&'a Type),
+}
+
+impl<'a> ArgumentCapture<'a> {
+    /// Returns how the given argument is captured or [`None`]
+    /// if its type contains lifetimes, references or type parameters of the trait.
+    /// Type parameters of the method are `'static` and captured if their bounds allow it.
+    pub(super) fn for_argument(
+        argument: &'a MethodArg,
+        method: &MethodDeclMetadata,
+    ) -> Option<Self> {
+        let capture = match &argument.ty {
+            Type::Reference(TypeReference {
+                mutability: None,
+                elem,
+                ..
+            }) => ArgumentCapture::Borrowed(elem),
+            Type::Reference(_) => return None,
+            ty => ArgumentCapture::Owned(ty),
+        };
+
+        let mut visitor = CapturableTypeVisitor {
+            generic_type_params: method
+                .generics
+                .type_params()
+                .map(|param| &param.ident)
+                .filter(|ident| {
+                    !method
+                        .method_decl
+                        .generics
+                        .type_params()
+                        .any(|param| param.ident == **ident)
+                })
+                .collect(),
+            capturable: true,
+        };
+        visitor.visit_type(capture.ty());
+
+        if visitor.capturable {
+            Some(capture)
+        } else {
+            None
+        }
+    }
+
+    fn ty(&self) -> &'a Type {
+        match self {
+            ArgumentCapture::Borrowed(ty) | ArgumentCapture::Owned(ty) => ty,
+        }
+    }
+
+    /// Generates an expression capturing the given argument as the given captured argument type.
+    pub(super) fn capture(
+        &self,
+        argument: TokenStream,
+        captured_argument: &TokenStream,
+    ) -> TokenStream {
+        let ty = self.ty();
+
+        let argument = match self {
+            ArgumentCapture::Borrowed(_) => argument,
+            ArgumentCapture::Owned(_) => quote! { &#argument },
+        };
+
+        quote! {
+            (&&mockiato::internal::ArgumentCaptureSelector::<#ty, #captured_argument>::new()).capture(#argument)
+        }
+    }
+
+    /// Generates an expression restoring an argument from the given captured argument.
+    pub(super) fn restore(
+        &self,
+        captured: TokenStream,
+        captured_argument: &TokenStream,
+    ) -> TokenStream {
+        let ty = self.ty();
+
+        let restore_method = match self {
+            ArgumentCapture::Borrowed(_) => quote! { restore_borrowed },
+            ArgumentCapture::Owned(_) => quote! { restore_owned },
+        };
+
+        quote! {
+            (&&mockiato::internal::ArgumentCaptureSelector::<#ty, #captured_argument>::new()).#restore_method(#captured)
+        }
+    }
+}
+
+struct CapturableTypeVisitor<'a> {
+    generic_type_params: Vec<&'a Ident>,
+    capturable: bool,
+}
+
+impl<'a, 'ast> Visit<'ast> for CapturableTypeVisitor<'a> {
+    fn visit_lifetime(&mut self, _lifetime: &'ast Lifetime) {
+        self.capturable = false;
+    }
+
+    fn visit_type(&mut self, ty: &'ast Type) {
+        match ty {
+            Type::Reference(_)
+            | Type::ImplTrait(_)
+            | Type::Infer(_)
+            | Type::Macro(_)
+            | Type::Verbatim(_) => self.capturable = false,
+            _ => visit_type(self, ty),
+        }
+    }
+
+    fn visit_path(&mut self, path: &'ast Path) {
+        let first_segment = path.segments.first().map(|pair| &pair.into_value().ident);
+
+        if let Some(first_segment) = first_segment {
+            if path.leading_colon.is_none()
+                && (first_segment == "Self" || self.generic_type_params.contains(&first_segment))
+            {
+                self.capturable = false;
+            }
+        }
+
+        visit_path(self, path);
+    }
+}
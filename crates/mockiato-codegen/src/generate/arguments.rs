@@ -1,5 +1,5 @@
-use super::constant::{arguments_ident, arguments_lifetime, arguments_lifetime_as_generic_param};
-use super::debug_impl::{generate_debug_impl, DebugImplField};
+use super::constant::{arguments_lifetime, arguments_lifetime_as_generic_param};
+use super::debug_impl::{debug_with, generate_debug_impl, DebugImplField};
 use super::lifetime_rewriter::{LifetimeRewriter, UniformLifetimeGenerator};
 use super::MethodDeclMetadata;
 use crate::parse::method_decl::MethodDecl;
@@ -7,7 +7,7 @@ use crate::parse::method_inputs::MethodInputs;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::visit_mut::visit_type_mut;
-use syn::{Generics, Visibility};
+use syn::{Generics, Ident, Visibility};
 
 pub(crate) fn generate_arguments(
     method: &MethodDeclMetadata,
@@ -32,13 +32,14 @@ pub(crate) fn generate_arguments(
         arguments_struct_ident,
         &generics,
     );
-    let display_impl = generate_display_impl(method_decl, &generics);
+    let display_impl = generate_display_impl(arguments_struct_ident, &generics);
+    let debug_arguments = generate_debug_arguments(method_decl);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let arguments_lifetime = arguments_lifetime();
 
     quote! {
         #[doc(hidden)]
-        #visibility struct #arguments_struct_ident #ty_generics #where_clause {
+        #visibility struct #arguments_struct_ident #impl_generics #where_clause {
             #arguments_fields
             pub(super) phantom_data: std::marker::PhantomData<&#arguments_lifetime ()>,
         }
@@ -46,38 +47,44 @@ pub(crate) fn generate_arguments(
         #display_impl
         #debug_impl
 
-        impl #impl_generics mockiato::internal::Arguments for #arguments_struct_ident #ty_generics #where_clause {}
+        impl #impl_generics mockiato::internal::Arguments for #arguments_struct_ident #ty_generics #where_clause {
+            fn debug_arguments(&self) -> Vec<String> {
+                vec![
+                    #debug_arguments
+                ]
+            }
+        }
     }
 }
 
 /// Generates a `Display` implementation for an arguments struct.
-fn generate_display_impl(method_decl: &MethodDecl, generics: &Generics) -> TokenStream {
-    let arguments_ident = arguments_ident(&method_decl.ident);
+fn generate_display_impl(arguments_ident: &Ident, generics: &Generics) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let display_fields: TokenStream = method_decl
-        .inputs
-        .args
-        .iter()
-        .map(|input| {
-            let ident = &input.ident;
-            quote! { format!("{:?}", &mockiato::internal::MaybeDebugWrapper(&self.#ident)), }
-        })
-        .collect();
-
     quote! {
         impl #impl_generics std::fmt::Display for #arguments_ident #ty_generics #where_clause {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                let arguments: Vec<String> = vec![
-                    #display_fields
-                ];
-
+                let arguments = mockiato::internal::Arguments::debug_arguments(self);
                 write!(f, "({})", arguments.join(", "))
             }
         }
     }
 }
 
+/// Generates the elements of a `Vec` containing the `Debug` representation of each argument.
+fn generate_debug_arguments(method_decl: &MethodDecl) -> TokenStream {
+    method_decl
+        .inputs
+        .args
+        .iter()
+        .map(|input| {
+            let ident = &input.ident;
+            let debug = debug_with(quote! { &self.#ident });
+            quote! { format!("{:?}", #debug), }
+        })
+        .collect()
+}
+
 fn debug_impl_fields<'a>(
     method_decl: &'a MethodDecl,
 ) -> impl Iterator<Item = DebugImplField<'a>> + 'a {
@@ -85,7 +92,7 @@ fn debug_impl_fields<'a>(
         let ident = &input.ident;
         DebugImplField {
             ident,
-            expression: quote! { mockiato::internal::MaybeDebugWrapper(&self.#ident) },
+            expression: debug_with(quote! { &self.#ident }),
         }
     })
 }
@@ -1,3 +1,4 @@
+use super::argument_capture::ArgumentCapture;
 use super::bound_lifetimes::rewrite_lifetimes_incrementally;
 use super::constant::{
     arguments_lifetime, arguments_lifetime_as_generic_param, arguments_matcher_ident,
@@ -5,7 +6,9 @@ use super::constant::{
 };
 use super::debug_impl::{generate_debug_impl, DebugImplField};
 use super::MethodDeclMetadata;
-use crate::generate::util::ident_to_string_literal;
+use crate::generate::util::{
+    captured_argument_type, ident_to_string_literal, thread_safety_bounds,
+};
 use crate::parse::method_decl::MethodDecl;
 use crate::parse::method_inputs::{MethodArg, MethodInputs};
 use proc_macro2::TokenStream;
@@ -16,6 +19,7 @@ use syn::{Generics, Token, Visibility};
 pub(crate) fn generate_arguments_matcher(
     method: &MethodDeclMetadata,
     visibility: &Visibility,
+    sync: bool,
 ) -> TokenStream {
     let MethodDeclMetadata {
         method_decl,
@@ -27,11 +31,11 @@ pub(crate) fn generate_arguments_matcher(
     let mut generics = generics.clone();
     generics.params.push(mock_lifetime_as_generic_param());
 
-    let arguments_matcher_fields = arguments_matcher_fields(&method_decl.inputs);
-    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let arguments_matcher_fields = arguments_matcher_fields(&method_decl.inputs, sync);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
 
     let display_impl = generate_display_impl(method_decl, &generics);
-    let arguments_matcher_impl = generate_arguments_matcher_impl(method, &generics);
+    let arguments_matcher_impl = generate_arguments_matcher_impl(method, &generics, sync);
 
     let debug_impl = generate_debug_impl(
         debug_impl_fields(method_decl),
@@ -43,7 +47,7 @@ pub(crate) fn generate_arguments_matcher(
 
     quote! {
         #[doc(hidden)]
-        #visibility struct #arguments_matcher_ident #ty_generics #where_clause {
+        #visibility struct #arguments_matcher_ident #impl_generics #where_clause {
             #arguments_matcher_fields
             pub(super) phantom_data: std::marker::PhantomData<&#mock_lifetime ()>,
         }
@@ -87,6 +91,7 @@ fn generate_display_impl(method_decl: &MethodDecl, generics: &Generics) -> Token
 fn generate_arguments_matcher_impl(
     method: &MethodDeclMetadata,
     generics_with_mock_lifetime: &Generics,
+    sync: bool,
 ) -> TokenStream {
     let MethodDeclMetadata {
         method_decl,
@@ -107,17 +112,27 @@ fn generate_arguments_matcher_impl(
         .push(arguments_lifetime_as_generic_param());
 
     let (impl_generics, _, _) = generics_with_arguments_lifetime.split_for_impl();
-    let (_, ty_generics, where_clause) = generics_with_mock_lifetime.split_for_impl();
+    let (impl_generics_with_mock_lifetime, ty_generics, where_clause) =
+        generics_with_mock_lifetime.split_for_impl();
     let (_, arguments_ty_generics, _) = arguments_struct_generics.split_for_impl();
 
+    let captured_argument = captured_argument_type(sync);
     let matches_argument_method = generate_matches_arguments_method_impl(method_decl);
+    let capture_arguments_methods =
+        generate_capture_arguments_methods_impl(method, &captured_argument);
     let arguments_lifetime = arguments_lifetime();
 
     quote! {
+        impl #impl_generics_with_mock_lifetime mockiato::internal::CaptureArguments for #arguments_matcher_ident #ty_generics #where_clause {
+            type CapturedArgument = #captured_argument;
+        }
+
         impl #impl_generics mockiato::internal::ArgumentsMatcher<#arguments_lifetime> for #arguments_matcher_ident #ty_generics #where_clause {
             type Arguments = #arguments_struct_ident #arguments_ty_generics;
 
             #matches_argument_method
+
+            #capture_arguments_methods
         }
     }
 }
@@ -131,10 +146,126 @@ fn generate_matches_arguments_method_impl(method_decl: &MethodDecl) -> TokenStre
         generate_matches_argument_calls(args)
     };
 
+    let argument_mismatches: TokenStream = args
+        .iter()
+        .map(|arg| {
+            let ident = &arg.ident;
+            let ident_as_str = ident_to_string_literal(ident);
+            quote! {
+                self.#ident
+                    .mismatch(&args.#ident)
+                    .map(|mismatch| mockiato::internal::ArgumentMismatch::new(#ident_as_str, mismatch)),
+            }
+        })
+        .collect();
+
+    let matching_arguments: TokenStream = args
+        .iter()
+        .map(|arg| {
+            let ident = &arg.ident;
+            quote! { + if self.#ident.matches_argument(&args.#ident) { 1 } else { 0 } }
+        })
+        .collect();
+
+    let specificity: TokenStream = args
+        .iter()
+        .map(|arg| {
+            let ident = &arg.ident;
+            quote! { + if self.#ident.is_wildcard() { 0 } else { 1 } }
+        })
+        .collect();
+
     quote! {
         fn matches_arguments(&self, args: &Self::Arguments) -> bool {
             #matches_argument_calls
         }
+
+        fn argument_mismatches(&self, args: &Self::Arguments) -> Vec<mockiato::internal::ArgumentMismatch> {
+            let argument_mismatches: Vec<Option<mockiato::internal::ArgumentMismatch>> = vec![
+                #argument_mismatches
+            ];
+
+            argument_mismatches.into_iter().flatten().collect()
+        }
+
+        fn specificity(&self) -> usize {
+            0 #specificity
+        }
+
+        fn matching_arguments(&self, args: &Self::Arguments) -> usize {
+            0 #matching_arguments
+        }
+    }
+}
+
+/// Generates `capture_arguments` and `restore_arguments` if all arguments can be captured.
+/// The default implementations are used otherwise.
+fn generate_capture_arguments_methods_impl(
+    method: &MethodDeclMetadata,
+    captured_argument: &TokenStream,
+) -> TokenStream {
+    let MethodDeclMetadata {
+        method_decl,
+        arguments_struct_ident,
+        ..
+    } = method;
+
+    let captures: Option<Vec<_>> = method_decl
+        .inputs
+        .args
+        .iter()
+        .map(|arg| ArgumentCapture::for_argument(arg, method).map(|capture| (arg, capture)))
+        .collect();
+
+    let captures = match captures {
+        Some(captures) => captures,
+        None => return TokenStream::new(),
+    };
+
+    let captured_arguments: TokenStream = captures
+        .iter()
+        .map(|(arg, capture)| {
+            let ident = &arg.ident;
+            let capture = capture.capture(quote! { input.#ident }, captured_argument);
+            quote! { #capture?, }
+        })
+        .collect();
+
+    let restored_arguments: TokenStream = captures
+        .iter()
+        .enumerate()
+        .map(|(index, (arg, capture))| {
+            let ident = &arg.ident;
+            let restore = capture.restore(quote! { captured.get(#index)? }, captured_argument);
+            quote! { #ident: #restore?, }
+        })
+        .collect();
+
+    let arguments_lifetime = arguments_lifetime();
+
+    quote! {
+        fn capture_arguments(input: &Self::Arguments) -> Option<Vec<#captured_argument>> {
+            use mockiato::internal::{SelectCaptureArgument as _, SelectNoCaptureArgument as _};
+
+            Some(vec![
+                #captured_arguments
+            ])
+        }
+
+        fn restore_arguments(
+            &self,
+            captured: &#arguments_lifetime [#captured_argument],
+        ) -> Option<Self::Arguments> {
+            use mockiato::internal::{
+                SelectCaptureArgument as _, SelectNoCaptureArgument as _,
+                SelectNoRestoreOwnedArgument as _, SelectRestoreOwnedArgument as _,
+            };
+
+            Some(#arguments_struct_ident {
+                #restored_arguments
+                phantom_data: std::marker::PhantomData,
+            })
+        }
     }
 }
 
@@ -149,8 +280,9 @@ fn generate_matches_argument_calls(args: &[MethodArg]) -> TokenStream {
     quote!(#matches_argument_calls)
 }
 
-fn arguments_matcher_fields(method_inputs: &MethodInputs) -> TokenStream {
+fn arguments_matcher_fields(method_inputs: &MethodInputs, sync: bool) -> TokenStream {
     let mock_lifetime = mock_lifetime();
+    let thread_safety_bounds = thread_safety_bounds(sync);
     method_inputs
         .args
         .iter()
@@ -160,7 +292,7 @@ fn arguments_matcher_fields(method_inputs: &MethodInputs) -> TokenStream {
             let bound_lifetimes = rewrite_lifetimes_incrementally(&mut ty);
 
             quote! {
-                pub(super) #ident: std::boxed::Box<dyn #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> + #mock_lifetime>,
+                pub(super) #ident: std::boxed::Box<dyn #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> #thread_safety_bounds + #mock_lifetime>,
             }
         })
         .collect()
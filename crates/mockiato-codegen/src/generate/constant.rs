@@ -65,6 +65,46 @@ pub(super) fn expect_method_ident(method_decl_ident: &Ident) -> Ident {
     )
 }
 
+/// Generates the identifier for an allow method
+pub(super) fn allow_method_ident(method_decl_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "allow_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident.to_string()),
+        method_decl_ident.span(),
+    )
+}
+
+/// Generates the identifier for a method verifying the recorded calls of a method
+pub(super) fn verify_method_ident(method_decl_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "verify_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident.to_string()),
+        method_decl_ident.span(),
+    )
+}
+
+/// Generates the identifier for a method returning the recorded calls of a method
+pub(super) fn calls_to_method_ident(method_decl_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "calls_to_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident.to_string()),
+        method_decl_ident.span(),
+    )
+}
+
+/// Generates the identifier for a method waiting for calls to a method
+pub(super) fn wait_for_method_ident(method_decl_ident: &Ident) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "wait_for_";
+
+    Ident::new(
+        &format!("{}{}", IDENTIFIER_PREFIX, method_decl_ident.to_string()),
+        method_decl_ident.span(),
+    )
+}
+
 /// Generates the method identifier for a method configuring calls to be expected sequentially.
 pub(super) fn expect_method_calls_in_order_ident(method_decl: &MethodDecl) -> Ident {
     const IDENTIFIER_PREFIX: &str = "expect_";
@@ -81,6 +121,35 @@ pub(super) fn expect_method_calls_in_order_ident(method_decl: &MethodDecl) -> Id
     )
 }
 
+/// Generates the method identifier for a method configuring the resolution of ambiguous calls.
+pub(super) fn set_ambiguity_resolution_ident(method_decl: &MethodDecl) -> Ident {
+    const IDENTIFIER_PREFIX: &str = "set_";
+    const IDENTIFIER_SUFFIX: &str = "_ambiguity_resolution";
+
+    Ident::new(
+        &format!(
+            "{}{}{}",
+            IDENTIFIER_PREFIX,
+            method_decl.ident.to_string(),
+            IDENTIFIER_SUFFIX
+        ),
+        method_decl.ident.span(),
+    )
+}
+
+/// Generates the identifiers of all methods that the mock struct generates for a method
+pub(crate) fn generated_method_idents(method_decl: &MethodDecl) -> Vec<Ident> {
+    vec![
+        expect_method_ident(&method_decl.ident),
+        allow_method_ident(&method_decl.ident),
+        verify_method_ident(&method_decl.ident),
+        calls_to_method_ident(&method_decl.ident),
+        wait_for_method_ident(&method_decl.ident),
+        expect_method_calls_in_order_ident(method_decl),
+        set_ambiguity_resolution_ident(method_decl),
+    ]
+}
+
 /// Generates the generic parameter for a given index
 pub(super) fn generic_parameter_ident(index: usize) -> Ident {
     const IDENTIFIER_PREFIX: &str = "A";
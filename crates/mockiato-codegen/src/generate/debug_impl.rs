@@ -1,7 +1,7 @@
 use crate::generate::util::ident_to_string_literal;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Generics, Ident};
+use syn::{Generics, Ident, Type};
 
 pub(crate) struct DebugImplField<'a> {
     pub(crate) ident: &'a Ident,
@@ -33,3 +33,30 @@ pub(crate) fn generate_debug_impl<'a>(
         }
     }
 }
+
+/// Generates an expression that selects the [`Debug`] implementation of the given type
+/// if it has one and falls back to a placeholder otherwise.
+/// This does not require specialization.
+pub(crate) fn select_debug_fn(ty: &Type) -> TokenStream {
+    quote! {
+        {
+            use mockiato::internal::{SelectDebugFn as _, SelectFallbackDebugFn as _};
+            (&&mockiato::internal::DebugFnSelector::<#ty>::new()).debug_fn()
+        }
+    }
+}
+
+/// Generates an expression that implements [`Debug`] for the value of
+/// the given expression, regardless of whether its type implements [`Debug`].
+pub(crate) fn debug_with(expression: TokenStream) -> TokenStream {
+    quote! {
+        {
+            use mockiato::internal::{SelectDebugFn as _, SelectFallbackDebugFn as _};
+            let value = #expression;
+            mockiato::internal::DebugWith(
+                value,
+                (&&mockiato::internal::DebugFnSelector::of(value)).debug_fn(),
+            )
+        }
+    }
+}
@@ -20,7 +20,7 @@ pub(crate) fn generate_drop_impl(
     quote! {
         impl #impl_generics Drop for #mock_ident #ty_generics #where_clause {
             fn drop(&mut self) {
-                if !std::thread::panicking() {
+                if !std::thread::panicking() && !self.disarmed {
                     #verify_calls
                 }
             }
@@ -28,7 +28,7 @@ pub(crate) fn generate_drop_impl(
     }
 }
 
-fn generate_verify_call(method_decl: &MethodDecl) -> TokenStream {
+pub(crate) fn generate_verify_call(method_decl: &MethodDecl) -> TokenStream {
     let ident = &method_decl.ident;
 
     quote! {
@@ -1,10 +1,13 @@
+use crate::parse::method_decl::MethodDecl;
 use crate::parse::method_inputs::MethodInputs;
+use crate::parse::trait_decl::TraitDecl;
 use crate::syn_ext::PathExt;
 use std::collections::HashSet;
+use syn::punctuated::Punctuated;
 use syn::visit::{visit_path, Visit};
 use syn::{
-    GenericParam, Generics, Ident, Path, PredicateType, Type, TypeParam, WhereClause,
-    WherePredicate,
+    parse_quote, GenericParam, Generics, Ident, Path, PredicateType, Token, Type, TypeParam,
+    TypeParamBound, WhereClause, WherePredicate,
 };
 
 pub(super) fn get_matching_generics_for_method_inputs(
@@ -18,9 +21,22 @@ pub(super) fn get_matching_generics_for_method_inputs(
         .as_ref()
         .map(|where_clause| filter_where_clause(where_clause, &matching_generic_types));
 
-    let params = matching_generic_types
-        .into_iter()
-        .map(|ident| GenericParam::Type(TypeParam::from(ident.clone())))
+    let params = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(TypeParam { ident, .. })
+                if matching_generic_types.contains(ident) =>
+            {
+                Some(GenericParam::Type(TypeParam::from(ident.clone())))
+            }
+            GenericParam::Const(const_param)
+                if matching_generic_types.contains(&const_param.ident) =>
+            {
+                Some(GenericParam::Const(const_param.clone()))
+            }
+            _ => None,
+        })
         .collect();
 
     Generics {
@@ -31,6 +47,115 @@ pub(super) fn get_matching_generics_for_method_inputs(
     }
 }
 
+/// Returns the generics of a trait extended by a type parameter for each associated type.
+/// The bounds of the associated types are moved to the where clause.
+pub(super) fn generics_with_associated_types(trait_decl: &TraitDecl) -> Generics {
+    let mut generics = trait_decl.generics.clone();
+
+    for associated_type in &trait_decl.associated_types {
+        let ident = &associated_type.ident;
+        let bounds = &associated_type.bounds;
+
+        if !bounds.is_empty() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#ident: #bounds));
+        }
+
+        generics
+            .params
+            .push(GenericParam::Type(TypeParam::from(ident.clone())));
+    }
+
+    generics
+}
+
+/// Combines the generics of a trait with the type parameters of one of its methods.
+/// The bounds of the type parameters are moved to the where clause,
+/// so that they are kept by [`get_matching_generics_for_method_inputs`].
+pub(super) fn generics_for_method_decl(
+    method_decl: &MethodDecl,
+    trait_generics: &Generics,
+) -> Generics {
+    let mut generics = trait_generics.clone();
+    let type_params = method_type_params(method_decl);
+    let where_clause = generics.make_where_clause();
+
+    for type_param in &type_params {
+        if let GenericParam::Type(TypeParam { ident, bounds, .. }) = type_param {
+            if !bounds.is_empty() {
+                where_clause.predicates.push(parse_quote!(#ident: #bounds));
+            }
+        }
+    }
+
+    where_clause
+        .predicates
+        .extend(method_where_predicates(method_decl));
+    generics.params.extend(type_params);
+
+    generics
+}
+
+/// Returns the type parameters of the given method with their bounds.
+/// Bounds referring to the lifetimes of the method are removed, because they are not in scope.
+pub(super) fn method_type_params(method_decl: &MethodDecl) -> Punctuated<GenericParam, Token![,]> {
+    method_decl
+        .generics
+        .type_params()
+        .map(|type_param| {
+            let mut type_param = type_param.clone();
+            type_param.bounds = type_param
+                .bounds
+                .into_iter()
+                .filter(|bound| !is_bound_on_method_lifetime(bound, method_decl))
+                .collect();
+            GenericParam::Type(type_param)
+        })
+        .collect()
+}
+
+/// Returns the type predicates of the where clause of the given method.
+/// Bounds referring to the lifetimes of the method are removed, because they are not in scope.
+pub(super) fn method_where_predicates(
+    method_decl: &MethodDecl,
+) -> Punctuated<WherePredicate, Token![,]> {
+    method_decl
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .filter_map(|predicate| match predicate {
+            WherePredicate::Type(predicate) => {
+                let mut predicate = predicate.clone();
+                predicate.bounds = predicate
+                    .bounds
+                    .into_iter()
+                    .filter(|bound| !is_bound_on_method_lifetime(bound, method_decl))
+                    .collect();
+
+                if predicate.bounds.is_empty() {
+                    None
+                } else {
+                    Some(WherePredicate::Type(predicate))
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_bound_on_method_lifetime(bound: &TypeParamBound, method_decl: &MethodDecl) -> bool {
+    match bound {
+        TypeParamBound::Lifetime(lifetime) => method_decl
+            .generics
+            .lifetimes()
+            .any(|lifetime_def| lifetime_def.lifetime == *lifetime),
+        TypeParamBound::Trait(_) => false,
+    }
+}
+
 fn filter_where_clause(
     WhereClause {
         predicates,
@@ -82,7 +207,11 @@ fn find_overlapping_generic_types<'a>(
     generics: &'a Generics,
 ) -> HashSet<&'a Ident> {
     let mut visitor = FindOverlappingGenericTypeIdents {
-        generic_type_idents_filter: generics.type_params().map(|param| &param.ident).collect(),
+        generic_type_idents_filter: generics
+            .type_params()
+            .map(|param| &param.ident)
+            .chain(generics.const_params().map(|param| &param.ident))
+            .collect(),
         overlapping_generic_type_idents: HashSet::new(),
     };
 
@@ -1,14 +1,16 @@
 use super::bound_lifetimes::rewrite_lifetimes_incrementally;
 use super::constant::{
-    arguments_matcher_ident, expect_method_calls_in_order_ident, expect_method_ident,
-    generic_parameter_ident, mock_lifetime, mock_lifetime_as_generic_param,
+    allow_method_ident, arguments_matcher_ident, calls_to_method_ident,
+    expect_method_calls_in_order_ident, expect_method_ident, generic_parameter_ident,
+    mock_lifetime, set_ambiguity_resolution_ident, verify_method_ident, wait_for_method_ident,
 };
-use super::debug_impl::{generate_debug_impl, DebugImplField};
-use super::generics::get_matching_generics_for_method_inputs;
+use super::debug_impl::{generate_debug_impl, select_debug_fn, DebugImplField};
+use super::drop_impl::generate_verify_call;
+use super::generics::{method_type_params, method_where_predicates};
 use super::lifetime_rewriter::{LifetimeRewriter, UniformLifetimeGenerator};
 use super::GenerateMockParameters;
 use super::MethodDeclMetadata;
-use crate::generate::util::doc_attribute;
+use crate::generate::util::{doc_attribute, thread_safety_bounds, track_caller_attribute};
 use crate::parse::method_decl::MethodDecl;
 use crate::parse::method_inputs::MethodArg;
 use crate::parse::trait_decl::TraitDecl;
@@ -16,7 +18,9 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::visit_mut::visit_type_mut;
-use syn::{parse_quote, GenericParam, Ident, LitStr, Token, Type, TypeParam, WherePredicate};
+use syn::{
+    parse_quote, GenericParam, Ident, Lifetime, LitStr, Token, Type, TypeParam, WherePredicate,
+};
 
 type ArgumentsWithGenerics<'a> = &'a [(Ident, &'a MethodArg)];
 
@@ -30,19 +34,47 @@ pub(crate) fn generate_mock_struct(
     let method_fields: TokenStream = parameters
         .methods
         .iter()
-        .map(|method| generate_method_field(method, mod_ident))
+        .map(|method| generate_method_field(method, mod_ident, parameters.sync))
         .collect();
 
     let initializer_fields: TokenStream = parameters
         .methods
         .iter()
-        .map(|method| generate_initializer_field(method, mock_struct_ident))
+        .map(|method| generate_initializer_field(method, parameters))
         .collect();
 
     let expect_methods: TokenStream = parameters
         .methods
         .iter()
-        .map(|method| generate_expect_method(method, trait_decl, mod_ident))
+        .map(|method| {
+            generate_expect_method(
+                method,
+                trait_decl,
+                parameters,
+                ExpectMethodKind::Expectation,
+            )
+        })
+        .collect();
+
+    let allow_methods: TokenStream = parameters
+        .methods
+        .iter()
+        .map(|method| {
+            generate_expect_method(method, trait_decl, parameters, ExpectMethodKind::Stub)
+        })
+        .collect();
+
+    let verify_methods: TokenStream = parameters
+        .methods
+        .iter()
+        .map(|method| {
+            generate_expect_method(
+                method,
+                trait_decl,
+                parameters,
+                ExpectMethodKind::Verification,
+            )
+        })
         .collect();
 
     let expect_method_call_in_order_methods: TokenStream = trait_decl
@@ -51,6 +83,14 @@ pub(crate) fn generate_mock_struct(
         .map(|method_decl| generate_expect_method_calls_in_order_method(trait_decl, method_decl))
         .collect();
 
+    let ambiguity_resolution_methods = generate_ambiguity_resolution_methods(trait_decl);
+
+    let strictness_methods = generate_strictness_methods(trait_decl);
+
+    let recorded_calls_methods = generate_recorded_calls_methods(trait_decl, parameters);
+
+    let verification_methods = generate_verification_methods(trait_decl);
+
     let debug_impl_fields = parameters
         .methods
         .iter()
@@ -71,29 +111,65 @@ pub(crate) fn generate_mock_struct(
         trait_decl.ident, GITHUB_REPOSITORY
     ));
 
+    let set_nice_strictness = if parameters.nice {
+        quote! {
+            let mut mock = mock;
+            mock.set_strictness(mockiato::Strictness::Nice);
+        }
+    } else {
+        TokenStream::new()
+    };
+
     let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
     let mock_lifetime = mock_lifetime();
 
+    // Associated types are not necessarily used by any method.
+    // Raw pointers are used to allow associated types that are not `Sized`.
+    let associated_types: TokenStream = trait_decl
+        .associated_types
+        .iter()
+        .map(|associated_type| {
+            let ident = &associated_type.ident;
+            quote! { *const #ident, }
+        })
+        .collect();
+
     quote! {
         #[derive(Clone)]
         #documentation
-        #visibility struct #mock_struct_ident #ty_generics #where_clause {
+        #visibility struct #mock_struct_ident #impl_generics #where_clause {
             #method_fields
-            phantom_data: std::marker::PhantomData<&#mock_lifetime ()>,
+            disarmed: bool,
+            phantom_data: std::marker::PhantomData<(&#mock_lifetime (), fn() -> (#associated_types))>,
         }
 
         impl #impl_generics #mock_struct_ident #ty_generics #where_clause {
             /// Creates a new mock with no expectations.
             #visibility fn new() -> Self {
-                Self {
+                let mock = Self {
                     #initializer_fields
+                    disarmed: false,
                     phantom_data: std::marker::PhantomData,
-                }
+                };
+                #set_nice_strictness
+                mock
             }
 
             #expect_methods
 
+            #allow_methods
+
+            #verify_methods
+
             #expect_method_call_in_order_methods
+
+            #ambiguity_resolution_methods
+
+            #strictness_methods
+
+            #recorded_calls_methods
+
+            #verification_methods
         }
 
         #debug_impl
@@ -108,67 +184,211 @@ pub(crate) fn generate_mock_struct(
 }
 
 fn generate_method_field(
-    MethodDeclMetadata {
-        method_decl: MethodDecl { ident, .. },
-        arguments_matcher_struct_ident,
-        generics,
-        return_type,
-        ..
-    }: &MethodDeclMetadata,
+    method: &MethodDeclMetadata,
     mod_ident: &Ident,
+    sync: bool,
 ) -> TokenStream {
-    let return_type = rewrite_lifetimes_to_mock_lifetime(return_type);
+    let ident = &method.method_decl.ident;
+
+    if method.method_decl.is_generic() {
+        let erased_method = if sync {
+            quote! { mockiato::internal::SyncErasedMethod }
+        } else {
+            quote! { mockiato::internal::ErasedMethod }
+        };
+
+        return quote! {
+            #ident: mockiato::internal::GenericMethod<dyn #erased_method>,
+        };
+    }
 
-    let mut generics = generics.clone();
-    generics.params.push(mock_lifetime_as_generic_param());
-    let (_, ty_generics, _) = generics.split_for_impl();
-
-    let mock_lifetime = mock_lifetime();
+    let lifetime = method_lifetime(&method.method_decl);
+    let arguments_matcher = arguments_matcher_type(method, mod_ident, &lifetime);
+    let return_type = rewrite_lifetimes(&method.return_type, &lifetime);
 
     quote! {
-        #ident: mockiato::internal::Method<#mock_lifetime, #mod_ident::#arguments_matcher_struct_ident #ty_generics, #return_type>,
+        #ident: mockiato::internal::Method<#lifetime, #arguments_matcher, #return_type>,
     }
 }
 
 fn generate_initializer_field(
     method: &MethodDeclMetadata,
-    mock_struct_ident: &Ident,
+    parameters: &GenerateMockParameters,
 ) -> TokenStream {
     let method_ident = &method.method_decl.ident;
+
+    if method.method_decl.is_generic() {
+        let name = method_name(&method.method_decl, &parameters.mock_struct_ident);
+
+        quote! {
+            #method_ident: mockiato::internal::GenericMethod::new(#name),
+        }
+    } else {
+        let method = generate_method_constructor(
+            method,
+            &parameters.mock_struct_ident,
+            &parameters.mod_ident,
+            parameters.default_returns,
+        );
+
+        quote! {
+            #method_ident: #method,
+        }
+    }
+}
+
+/// Generates an expression that creates the [`Method`] used for the given method
+/// or for an instantiation of the given generic method.
+fn generate_method_constructor(
+    method: &MethodDeclMetadata,
+    mock_struct_ident: &Ident,
+    mod_ident: &Ident,
+    default_returns: bool,
+) -> TokenStream {
+    let lifetime = method_lifetime(&method.method_decl);
+    let arguments_matcher = arguments_matcher_type(method, mod_ident, &lifetime);
+    let return_type = rewrite_lifetimes(&method.return_type, &lifetime);
+    let debug_return_value = select_debug_fn(&return_type);
+    let name = method_name(&method.method_decl, mock_struct_ident);
+
+    let default_return_value = if default_returns {
+        let default_return_value = select_default_return_value(&return_type);
+        Some(quote! { .with_default_return_value(#default_return_value) })
+    } else if is_empty_return_value(&return_type) {
+        Some(quote! { .with_default_return_value(Some(|| ())) })
+    } else {
+        None
+    };
+
+    let nice_return_value = select_default_return_value(&return_type);
+
+    quote! {
+        mockiato::internal::Method::<#lifetime, #arguments_matcher, #return_type>::new(
+            #name,
+            #debug_return_value,
+        )#default_return_value
+        .with_nice_return_value(#nice_return_value)
+    }
+}
+
+fn method_name(method_decl: &MethodDecl, mock_struct_ident: &Ident) -> TokenStream {
     let name = LitStr::new(
         &format!(
             "{}::{}",
             mock_struct_ident.to_string(),
-            method_ident.to_string()
+            method_decl.ident.to_string()
         ),
         Span::call_site(),
     );
 
-    quote! {
-        #method_ident: mockiato::internal::Method::new(#name),
+    quote! { concat!(module_path!(), "::", #name) }
+}
+
+/// Generates a closure that creates the instantiation of a generic method
+/// for the type arguments that are in scope.
+pub(super) fn generate_instantiation_constructor(
+    method: &MethodDeclMetadata,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let method = generate_method_constructor(
+        method,
+        &parameters.mock_struct_ident,
+        &parameters.mod_ident,
+        parameters.default_returns,
+    );
+
+    quote! { || Box::new(#method) }
+}
+
+/// Generates the type arguments that identify the instantiation of a generic method,
+/// followed by the types of its arguments matcher and return value.
+pub(super) fn instantiation_type_arguments(
+    method: &MethodDeclMetadata,
+    mod_ident: &Ident,
+) -> TokenStream {
+    let type_params = method
+        .method_decl
+        .generics
+        .type_params()
+        .map(|type_param| &type_param.ident);
+    let lifetime = method_lifetime(&method.method_decl);
+    let arguments_matcher = arguments_matcher_type(method, mod_ident, &lifetime);
+    let return_type = rewrite_lifetimes(&method.return_type, &lifetime);
+
+    quote! { (#(#type_params,)*), #arguments_matcher, #return_type }
+}
+
+fn arguments_matcher_type(
+    method: &MethodDeclMetadata,
+    mod_ident: &Ident,
+    lifetime: &Lifetime,
+) -> TokenStream {
+    let arguments_matcher_ident = &method.arguments_matcher_struct_ident;
+    let generic_params = method
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(type_param) => Some(&type_param.ident),
+            GenericParam::Const(const_param) => Some(&const_param.ident),
+            GenericParam::Lifetime(_) => None,
+        });
+
+    quote! { #mod_ident::#arguments_matcher_ident<#lifetime #(, #generic_params)*> }
+}
+
+/// Returns the lifetime of the arguments matcher and return value of the given method.
+/// The instantiations of generic methods are stored type-erased, which requires them to be `'static`.
+fn method_lifetime(method_decl: &MethodDecl) -> Lifetime {
+    if method_decl.is_generic() {
+        parse_quote!('static)
+    } else {
+        mock_lifetime()
     }
 }
 
+/// Distinguishes the `expect_*` methods from the `allow_*` and `verify_*` methods
+#[derive(Clone, Copy)]
+enum ExpectMethodKind {
+    Expectation,
+    Stub,
+    Verification,
+}
+
 fn generate_expect_method(
-    MethodDeclMetadata {
-        return_type,
-        method_decl:
-            MethodDecl {
-                ident: method_ident,
-                inputs,
-                ..
-            },
-        ..
-    }: &MethodDeclMetadata,
+    method: &MethodDeclMetadata,
     TraitDecl {
         visibility,
-        generics,
         ident: trait_ident,
         ..
     }: &TraitDecl,
-    mod_ident: &Ident,
+    parameters: &GenerateMockParameters,
+    kind: ExpectMethodKind,
 ) -> TokenStream {
-    let expect_method_ident = expect_method_ident(method_ident);
+    let MethodDeclMetadata {
+        method_decl,
+        return_type,
+        ..
+    } = method;
+    let MethodDecl {
+        ident: method_ident,
+        inputs,
+        ..
+    } = method_decl;
+    let GenerateMockParameters {
+        mod_ident, sync, ..
+    } = parameters;
+
+    let (expect_method_ident, add_call_method_ident) = match kind {
+        ExpectMethodKind::Expectation => (
+            expect_method_ident(method_ident),
+            quote! { add_expected_call },
+        ),
+        ExpectMethodKind::Stub => (allow_method_ident(method_ident), quote! { add_stub }),
+        ExpectMethodKind::Verification => {
+            (verify_method_ident(method_ident), quote! { verify_calls })
+        }
+    };
 
     let arguments_with_generics: Vec<_> = inputs
         .args
@@ -183,7 +403,9 @@ fn generate_expect_method(
         .collect();
 
     let arguments_matcher_ident = arguments_matcher_ident(method_ident);
-    let return_type = rewrite_lifetimes_to_mock_lifetime(return_type);
+    let lifetime = method_lifetime(method_decl);
+    let arguments_matcher = arguments_matcher_type(method, mod_ident, &lifetime);
+    let return_type = rewrite_lifetimes(return_type, &lifetime);
 
     let expected_parameters: TokenStream = arguments_with_generics
         .iter()
@@ -191,53 +413,118 @@ fn generate_expect_method(
         .map(|argument_ident| quote! { #argument_ident: Box::new(#argument_ident(&argument)), })
         .collect();
 
-    let requires_must_use_annotation = !is_empty_return_value(&return_type);
+    let requires_must_use_annotation = match kind {
+        ExpectMethodKind::Expectation | ExpectMethodKind::Stub => {
+            !is_empty_return_value(&return_type)
+        }
+        ExpectMethodKind::Verification => false,
+    };
 
+    // Whether the return type implements `Default` is only known at runtime,
+    // which is why the message does not assume that a return value is missing.
     let must_use_annotation = if requires_must_use_annotation {
-        quote! { #[must_use] }
+        quote! {
+            #[must_use = "The return value and the number of calls can be configured using the returned builder"]
+        }
     } else {
         TokenStream::new()
     };
 
-    let documentation = doc_attribute(format!(
-        "Expects a call to [`{0}::{1}`],
+    let documentation = match kind {
+        ExpectMethodKind::Expectation => doc_attribute(format!(
+            "Expects a call to [`{0}::{1}`],
 panicking if the function was not called by the time the object goes out of scope.
 
 [`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
-        trait_ident, method_ident,
+            trait_ident, method_ident,
+        )),
+        ExpectMethodKind::Stub => doc_attribute(format!(
+            "Allows any amount of calls to [`{0}::{1}`] without verifying them.
+Expected calls take precedence over allowed calls.
+
+[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
+            trait_ident, method_ident,
+        )),
+        ExpectMethodKind::Verification => doc_attribute(format!(
+            "Verifies the recorded calls to [`{0}::{1}`] matching the given argument matchers.
+Exactly one matching call is expected unless another number is given using `times`.
+Without `times`, the number of calls is checked when the returned verification is dropped,
+which happens at the end of the statement for `mock.verify_{1}(..);`.
+
+[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
+            trait_ident, method_ident,
+        )),
+    };
+
+    let mut generics = method_type_params(method_decl);
+    generics.extend(argument_generics(&arguments_with_generics));
+    let mut where_clause = method_where_predicates(method_decl);
+    where_clause.extend(where_clause_predicates(
+        &arguments_with_generics,
+        &lifetime,
+        *sync,
     ));
 
-    let mut arguments_struct_generics = get_matching_generics_for_method_inputs(inputs, generics);
-    arguments_struct_generics
-        .params
-        .push(mock_lifetime_as_generic_param());
-    let generics = argument_generics(&arguments_with_generics);
-    let where_clause = where_clause(&arguments_with_generics);
+    let track_caller_attribute = track_caller_attribute();
 
-    let (_, ty_generics, _) = arguments_struct_generics.split_for_impl();
-    let mock_lifetime = mock_lifetime();
+    let (receiver, builder) = match kind {
+        ExpectMethodKind::Expectation | ExpectMethodKind::Stub => (
+            quote! { &mut self },
+            quote! { mockiato::internal::MethodCallBuilder },
+        ),
+        ExpectMethodKind::Verification => (
+            quote! { &self },
+            quote! { mockiato::internal::CallVerification },
+        ),
+    };
+
+    let arguments_matcher_value = quote! {
+        #mod_ident::#arguments_matcher_ident {
+            #expected_parameters
+            phantom_data: std::marker::PhantomData,
+        }
+    };
+
+    let body = if method_decl.is_generic() {
+        let type_arguments = instantiation_type_arguments(method, mod_ident);
+        let create_instantiation = generate_instantiation_constructor(method, parameters);
+
+        match kind {
+            ExpectMethodKind::Expectation | ExpectMethodKind::Stub => quote! {
+                self.#method_ident
+                    .instantiation_mut::<#type_arguments, _>(#create_instantiation)
+                    .#add_call_method_ident(#arguments_matcher_value)
+            },
+            ExpectMethodKind::Verification => quote! {
+                self.#method_ident.verify_calls::<#type_arguments, _>(
+                    #create_instantiation,
+                    #arguments_matcher_value,
+                )
+            },
+        }
+    } else {
+        quote! {
+            self.#method_ident.#add_call_method_ident(#arguments_matcher_value)
+        }
+    };
 
     quote! {
         #must_use_annotation
         #documentation
+        #track_caller_attribute
         #visibility fn #expect_method_ident <#generics> (
-            &mut self,
+            #receiver,
             #arguments
-        ) -> mockiato::internal::MethodCallBuilder<
-            #mock_lifetime,
+        ) -> #builder<
+            #lifetime,
             '_,
-            #mod_ident::#arguments_matcher_ident #ty_generics,
+            #arguments_matcher,
             #return_type
         > where #where_clause
         {
             #[allow(dead_code)]
             let argument = mockiato::Argument::internal_new();
-            self.#method_ident.add_expected_call(
-                #mod_ident::#arguments_matcher_ident {
-                    #expected_parameters
-                    phantom_data: std::marker::PhantomData,
-                }
-            )
+            #body
         }
     }
 }
@@ -266,6 +553,274 @@ fn generate_expect_method_calls_in_order_method(
     }
 }
 
+fn generate_ambiguity_resolution_methods(trait_decl: &TraitDecl) -> TokenStream {
+    let visibility = &trait_decl.visibility;
+
+    let set_method_ambiguity_resolution_methods: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(|method_decl| generate_set_method_ambiguity_resolution_method(trait_decl, method_decl))
+        .collect();
+
+    let set_ambiguity_resolution_calls: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(|method_decl| {
+            let ident = &method_decl.ident;
+            quote! { self.#ident.set_ambiguity_resolution(ambiguity_resolution); }
+        })
+        .collect();
+
+    quote! {
+        /// Configures which expected call is used for calls to any method
+        /// that match more than one expected call.
+        #visibility fn set_ambiguity_resolution(
+            &mut self,
+            ambiguity_resolution: mockiato::AmbiguityResolution,
+        ) {
+            #set_ambiguity_resolution_calls
+        }
+
+        #set_method_ambiguity_resolution_methods
+    }
+}
+
+fn generate_set_method_ambiguity_resolution_method(
+    trait_decl: &TraitDecl,
+    method_decl: &MethodDecl,
+) -> TokenStream {
+    let documentation = doc_attribute(format!(
+        "Configures which expected call is used for calls to [`{0}::{1}`]
+that match more than one expected call.
+
+[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
+        trait_decl.ident, method_decl.ident,
+    ));
+
+    let visibility = &trait_decl.visibility;
+
+    let ident = set_ambiguity_resolution_ident(method_decl);
+    let method_ident = &method_decl.ident;
+
+    quote! {
+        #documentation
+        #visibility fn #ident(&mut self, ambiguity_resolution: mockiato::AmbiguityResolution) {
+            self.#method_ident.set_ambiguity_resolution(ambiguity_resolution)
+        }
+    }
+}
+
+fn generate_strictness_methods(trait_decl: &TraitDecl) -> TokenStream {
+    let visibility = &trait_decl.visibility;
+
+    let set_strictness_calls: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(|method_decl| {
+            let ident = &method_decl.ident;
+            quote! { self.#ident.set_strictness(strictness); }
+        })
+        .collect();
+
+    let collect_unexpected_calls: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(|method_decl| {
+            let ident = &method_decl.ident;
+            quote! { unexpected_calls.extend(self.#ident.unexpected_calls()); }
+        })
+        .collect();
+
+    quote! {
+        /// Configures how calls that don't match any expected call are handled.
+        #visibility fn set_strictness(&mut self, strictness: mockiato::Strictness) {
+            #set_strictness_calls
+        }
+
+        /// Returns the calls that did not match any expected call while this mock was nice,
+        /// in the order in which they were made.
+        #visibility fn unexpected_calls(&self) -> Vec<String> {
+            let mut unexpected_calls: Vec<(usize, String)> = Vec::new();
+            #collect_unexpected_calls
+            unexpected_calls.sort_by_key(|&(call_number, _)| call_number);
+            unexpected_calls.into_iter().map(|(_, call)| call).collect()
+        }
+    }
+}
+
+fn generate_recorded_calls_methods(
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let visibility = &trait_decl.visibility;
+
+    let calls_to_methods: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(|method_decl| generate_calls_to_method(trait_decl, method_decl))
+        .collect();
+
+    let wait_for_methods: TokenStream = parameters
+        .methods
+        .iter()
+        .map(|method| generate_wait_for_method(trait_decl, method, parameters))
+        .collect();
+
+    let collect_recorded_calls: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(|method_decl| {
+            let ident = &method_decl.ident;
+            quote! { recorded_calls.extend(self.#ident.recorded_calls()); }
+        })
+        .collect();
+
+    quote! {
+        /// Returns all calls that were made to this mock, in the order in which they were made.
+        #visibility fn recorded_calls(&self) -> Vec<mockiato::RecordedCall> {
+            let mut recorded_calls: Vec<mockiato::RecordedCall> = Vec::new();
+            #collect_recorded_calls
+            recorded_calls.sort_by_key(mockiato::RecordedCall::sequence_number);
+            recorded_calls
+        }
+
+        #calls_to_methods
+
+        #wait_for_methods
+    }
+}
+
+fn generate_calls_to_method(trait_decl: &TraitDecl, method_decl: &MethodDecl) -> TokenStream {
+    let documentation = doc_attribute(format!(
+        "Returns the calls that were made to [`{0}::{1}`], in the order in which they were made.
+
+[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
+        trait_decl.ident, method_decl.ident,
+    ));
+
+    let visibility = &trait_decl.visibility;
+
+    let ident = calls_to_method_ident(&method_decl.ident);
+    let method_ident = &method_decl.ident;
+
+    quote! {
+        #documentation
+        #visibility fn #ident(&self) -> Vec<mockiato::RecordedCall> {
+            self.#method_ident.recorded_calls()
+        }
+    }
+}
+
+fn generate_wait_for_method(
+    trait_decl: &TraitDecl,
+    method: &MethodDeclMetadata,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let method_decl = &method.method_decl;
+    let documentation = doc_attribute(format!(
+        "Blocks until at least `count` calls were made to [`{0}::{1}`], e.g. by another thread.
+
+# Panics
+Panics if the calls are not made before the timeout elapses.
+
+[`{0}::{1}`]: ./trait.{0}.html#tymethod.{1}",
+        trait_decl.ident, method_decl.ident,
+    ));
+
+    let visibility = &trait_decl.visibility;
+
+    let ident = wait_for_method_ident(&method_decl.ident);
+    let method_ident = &method_decl.ident;
+    let track_caller = track_caller_attribute();
+
+    if method_decl.is_generic() {
+        let generics = method_type_params(method_decl);
+        let where_clause = method_where_predicates(method_decl);
+        let type_arguments = instantiation_type_arguments(method, &parameters.mod_ident);
+        let create_instantiation = generate_instantiation_constructor(method, parameters);
+
+        quote! {
+            #documentation
+            #track_caller
+            #visibility fn #ident<#generics>(&self, count: u64, timeout: std::time::Duration)
+            where #where_clause
+            {
+                self.#method_ident.with_instantiation::<#type_arguments, _, _, _>(
+                    #create_instantiation,
+                    |method| method.wait_for_calls(count, timeout),
+                );
+            }
+        }
+    } else {
+        quote! {
+            #documentation
+            #track_caller
+            #visibility fn #ident(&self, count: u64, timeout: std::time::Duration) {
+                self.#method_ident.wait_for_calls(count, timeout);
+            }
+        }
+    }
+}
+
+fn generate_verification_methods(trait_decl: &TraitDecl) -> TokenStream {
+    let visibility = &trait_decl.visibility;
+
+    let verify_calls: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(generate_verify_call)
+        .collect();
+
+    let clear_calls: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(|method_decl| {
+            let ident = &method_decl.ident;
+            quote! { self.#ident.clear_expected_calls(); }
+        })
+        .collect();
+
+    let verify_into_report: TokenStream = trait_decl
+        .methods
+        .iter()
+        .map(|method_decl| {
+            let ident = &method_decl.ident;
+            quote! { self.#ident.verify_into(&mut report); }
+        })
+        .collect();
+
+    quote! {
+        /// Verifies that all expected calls were called the expected number of times
+        /// without panicking. The returned report contains every method that failed verification.
+        #visibility fn try_verify(&self) -> Result<(), mockiato::MockVerificationReport> {
+            let mut report = mockiato::MockVerificationReport::new();
+            #verify_into_report
+            report.into_result()
+        }
+
+        /// Disables the verification of expected calls when this mock is dropped.
+        #visibility fn disarm(&mut self) {
+            self.disarmed = true;
+        }
+
+        /// Verifies that all expected calls were called the expected number of times
+        /// and removes them, so that new expected calls can be defined.
+        /// Stubs and the order of expected calls are reset as well,
+        /// while recorded calls and the strictness of the mock are kept.
+        #visibility fn checkpoint(&mut self) {
+            #verify_calls
+            #clear_calls
+        }
+
+        /// Runs the given closure and calls [`checkpoint`](#method.checkpoint) when it returns.
+        /// This can be used to split a test into phases with separate expected calls.
+        #visibility fn phase(&mut self, phase: impl FnOnce(&mut Self)) {
+            phase(self);
+            self.checkpoint();
+        }
+    }
+}
+
 fn debug_impl_field(method_decl: &MethodDecl) -> DebugImplField<'_> {
     let ident = &method_decl.ident;
     DebugImplField {
@@ -281,25 +836,30 @@ fn is_empty_return_value(return_type: &Type) -> bool {
     }
 }
 
-fn where_clause(arguments: ArgumentsWithGenerics<'_>) -> Punctuated<WherePredicate, Token![,]> {
+fn where_clause_predicates<'a>(
+    arguments: ArgumentsWithGenerics<'a>,
+    lifetime: &'a Lifetime,
+    sync: bool,
+) -> impl Iterator<Item = WherePredicate> + 'a {
     arguments
         .iter()
-        .map(|(generic_type_ident, method_argument)| {
-            where_clause_predicate(generic_type_ident, method_argument)
+        .map(move |(generic_type_ident, method_argument)| {
+            where_clause_predicate(generic_type_ident, method_argument, lifetime, sync)
         })
-        .collect()
 }
 
 fn where_clause_predicate(
     generic_type_ident: &Ident,
     method_argument: &MethodArg,
+    lifetime: &Lifetime,
+    sync: bool,
 ) -> WherePredicate {
     let mut ty = method_argument.ty.clone();
     let bound_lifetimes = rewrite_lifetimes_incrementally(&mut ty);
-    let mock_lifetime = mock_lifetime();
+    let thread_safety_bounds = thread_safety_bounds(sync);
 
     parse_quote! {
-        #generic_type_ident: #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> + #mock_lifetime
+        #generic_type_ident: #bound_lifetimes mockiato::internal::ArgumentMatcher<#ty> #thread_safety_bounds + #lifetime
     }
 }
 
@@ -320,10 +880,21 @@ fn generate_argument((generic_type_ident, method_argument): &(Ident, &MethodArg)
     }
 }
 
-fn rewrite_lifetimes_to_mock_lifetime(ty: &Type) -> Type {
+/// Generates an expression that selects [`Default::default`] as default return value
+/// if the given type implements [`Default`].
+fn select_default_return_value(ty: &Type) -> TokenStream {
+    quote! {
+        {
+            use mockiato::internal::{SelectDefaultReturnValue as _, SelectNoDefaultReturnValue as _};
+            (&&mockiato::internal::DefaultReturnValueSelector::<#ty>::new()).default_return_value()
+        }
+    }
+}
+
+fn rewrite_lifetimes(ty: &Type, lifetime: &Lifetime) -> Type {
     let mut ty = ty.clone();
     let mut lifetime_rewriter =
-        LifetimeRewriter::new(UniformLifetimeGenerator::new(mock_lifetime()));
+        LifetimeRewriter::new(UniformLifetimeGenerator::new(lifetime.clone()));
     visit_type_mut(&mut lifetime_rewriter, &mut ty);
     ty
 }
@@ -1,11 +1,14 @@
+use super::mock_struct::{generate_instantiation_constructor, instantiation_type_arguments};
 use super::GenerateMockParameters;
 use super::MethodDeclMetadata;
 use crate::parse::method_decl::MethodDecl;
+use crate::parse::return_kind::ReturnKind;
 use crate::parse::trait_decl::TraitDecl;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::punctuated::Punctuated;
-use syn::{Ident, Token};
+use syn::ReturnType;
+use syn::Token;
 
 pub(crate) fn generate_trait_impl(
     trait_decl: &TraitDecl,
@@ -18,35 +21,68 @@ pub(crate) fn generate_trait_impl(
     let method_impls: TokenStream = parameters
         .methods
         .iter()
-        .map(|method| generate_method_impl(method, &parameters.mod_ident))
+        .map(|method| generate_method_impl(method, trait_decl, parameters))
+        .collect();
+
+    let associated_types: TokenStream = trait_decl
+        .associated_types
+        .iter()
+        .map(|associated_type| {
+            let ident = &associated_type.ident;
+            quote! { type #ident = #ident; }
+        })
+        .collect();
+
+    // Associated consts without a value in the attribute keep the default of the trait.
+    let associated_consts: TokenStream = trait_decl
+        .associated_consts
+        .iter()
+        .filter_map(|associated_const| {
+            let const_value = parameters
+                .associated_const_values
+                .iter()
+                .find(|const_value| const_value.ident == associated_const.ident)?;
+            let ident = &associated_const.ident;
+            let ty = &associated_const.ty;
+            let value = &const_value.value;
+            Some(quote! { const #ident: #ty = #value; })
+        })
         .collect();
 
     let (impl_generics, ty_generics, where_clause) = parameters.generics.split_for_impl();
     let (_, trait_ty_generics, _) = trait_decl.generics.split_for_impl();
+    let async_trait_attr = &trait_decl.async_trait_attr;
 
     quote! {
+        #async_trait_attr
         #unsafety impl #impl_generics #trait_ident #trait_ty_generics for #mock_struct_ident #ty_generics #where_clause {
+            #associated_types
+            #associated_consts
             #method_impls
         }
     }
 }
 
 fn generate_method_impl(
-    MethodDeclMetadata {
+    method: &MethodDeclMetadata,
+    trait_decl: &TraitDecl,
+    parameters: &GenerateMockParameters,
+) -> TokenStream {
+    let MethodDeclMetadata {
         arguments_struct_ident,
-        method_decl:
-            MethodDecl {
-                ident,
-                unsafety,
-                generics,
-                inputs,
-                output,
-                ..
-            },
+        method_decl,
         ..
-    }: &MethodDeclMetadata,
-    mod_ident: &Ident,
-) -> TokenStream {
+    } = method;
+    let MethodDecl {
+        ident,
+        unsafety,
+        generics,
+        inputs,
+        output,
+        return_kind,
+        ..
+    } = method_decl;
+    let mod_ident = &parameters.mod_ident;
     let self_arg = &inputs.self_arg;
     let arguments: Punctuated<_, Token![,]> = inputs.args.iter().collect();
 
@@ -61,14 +97,64 @@ fn generate_method_impl(
         })
         .collect();
 
-    quote! {
-        #unsafety fn #ident#impl_generics(#self_arg, #arguments) #output #where_clause {
-            self.#ident.call_unwrap(
-                #mod_ident::#arguments_struct_ident {
-                    #arguments_struct_fields
-                    phantom_data: std::marker::PhantomData,
-                }
+    let arguments_struct = quote! {
+        #mod_ident::#arguments_struct_ident {
+            #arguments_struct_fields
+            phantom_data: std::marker::PhantomData,
+        }
+    };
+
+    let call = match return_kind {
+        ReturnKind::Value => quote! { call_unwrap },
+        _ => quote! { call_async_unwrap },
+    };
+
+    // The arguments of a generic method are moved into the closure receiving the instantiation,
+    // because they must not outlive the borrow of the instantiation.
+    let call = if method_decl.is_generic() {
+        let type_arguments = instantiation_type_arguments(method, mod_ident);
+        let create_instantiation = generate_instantiation_constructor(method, parameters);
+
+        quote! {
+            self.#ident.with_instantiation::<#type_arguments, _, _, _>(
+                #create_instantiation,
+                move |method| method.#call(#arguments_struct),
             )
         }
+    } else {
+        quote! { self.#ident.#call(#arguments_struct) }
+    };
+
+    // Async methods are implemented as methods returning `impl Future`, so that the call
+    // is made when the method is called and not when the future is first polled.
+    // `#[async_trait]` only desugars `async fn`s, which is why they are kept in this case.
+    let (asyncness, output, body) = match (return_kind, output) {
+        (ReturnKind::Async, _) if trait_decl.async_trait_attr.is_some() => (
+            Some(quote! { async }),
+            quote! { #output },
+            quote! { #call.await },
+        ),
+        (ReturnKind::Async, ReturnType::Default) => (
+            None,
+            quote! { -> impl std::future::Future<Output = ()> },
+            call,
+        ),
+        (ReturnKind::Async, ReturnType::Type(_, ty)) => (
+            None,
+            quote! { -> impl std::future::Future<Output = #ty> },
+            call,
+        ),
+        (ReturnKind::BoxedFuture(_), output) => {
+            (None, quote! { #output }, quote! { Box::pin(#call) })
+        }
+        (ReturnKind::Value, output) | (ReturnKind::ImplFuture(_), output) => {
+            (None, quote! { #output }, call)
+        }
+    };
+
+    quote! {
+        #asyncness #unsafety fn #ident#impl_generics(#self_arg, #arguments) #output #where_clause {
+            #body
+        }
     }
 }
@@ -1,4 +1,5 @@
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
 use syn::{parse_quote, Attribute, GenericParam, Ident, Lifetime, LifetimeDef, LitStr};
 
 pub(super) fn doc_attribute(content: String) -> Attribute {
@@ -16,3 +17,37 @@ pub(super) fn ident_to_string_literal(ident: &Ident) -> LitStr {
 pub(super) fn lifetime_to_generic_param(lifetime: Lifetime) -> GenericParam {
     GenericParam::Lifetime(LifetimeDef::new(lifetime))
 }
+
+/// Generates the bounds that make argument matchers
+/// [`Send`] and [`Sync`] if the mock is shared between threads.
+pub(super) fn thread_safety_bounds(sync: bool) -> TokenStream {
+    if sync {
+        quote! { + Send + Sync }
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// Generates the type used to capture arguments,
+/// which must be [`Send`] and [`Sync`] if the mock is shared between threads.
+pub(super) fn captured_argument_type(sync: bool) -> TokenStream {
+    if sync {
+        quote! { mockiato::internal::SyncCapturedArgument }
+    } else {
+        quote! { mockiato::internal::CapturedArgument }
+    }
+}
+
+/// Generates a `#[track_caller]` attribute
+/// if it is supported by the compiler that compiles the generated code.
+#[cfg(rustc_has_track_caller)]
+pub(super) fn track_caller_attribute() -> TokenStream {
+    quote! { #[track_caller] }
+}
+
+/// Generates a `#[track_caller]` attribute
+/// if it is supported by the compiler that compiles the generated code.
+#[cfg(not(rustc_has_track_caller))]
+pub(super) fn track_caller_attribute() -> TokenStream {
+    TokenStream::new()
+}
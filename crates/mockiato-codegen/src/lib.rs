@@ -27,16 +27,17 @@ mod result;
 mod syn_ext;
 
 use self::mockable::Mockable;
+use self::parse::mockable_attr::MockableAttrArgs;
 use crate::emit_diagnostics::emit_diagnostics;
 use proc_macro::TokenStream as ProcMacroTokenStream;
-use syn::{parse_macro_input, AttributeArgs, Item};
+use syn::{parse_macro_input, Item};
 
 #[doc(hidden)]
 #[proc_macro_attribute]
 pub fn mockable(args: ProcMacroTokenStream, input: ProcMacroTokenStream) -> ProcMacroTokenStream {
     let original_input = input.clone();
 
-    let attr = parse_macro_input!(args as AttributeArgs);
+    let attr = parse_macro_input!(args as MockableAttrArgs);
     let item = parse_macro_input!(input as Item);
 
     let mockable = Mockable::new();
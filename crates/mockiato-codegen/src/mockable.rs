@@ -1,12 +1,15 @@
+use crate::constant::{ATTR_NAME, MOCK_STRUCT_MEMBER_NAMES};
 use crate::diagnostic::DiagnosticBuilder;
-use crate::generate::{generate_mock, GenerateMockOptions};
-use crate::parse::mockable_attr::MockableAttr;
+use crate::generate::{generate_mock, generated_method_idents, GenerateMockOptions};
+use crate::parse::const_attr::ConstAttr;
+use crate::parse::method_decl::MethodDecl;
+use crate::parse::mockable_attr::{MockableAttr, MockableAttrArgs};
 use crate::parse::trait_decl::TraitDecl;
 use crate::result::Error;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::spanned::Spanned;
-use syn::{AttributeArgs, Item, ItemTrait};
+use syn::{Ident, Item, ItemTrait};
 
 #[derive(Default)]
 pub(crate) struct Mockable;
@@ -16,16 +19,22 @@ impl Mockable {
         Self::default()
     }
 
-    pub(crate) fn expand(&self, attr: AttributeArgs, item: Item) -> Result<TokenStream, Error> {
+    pub(crate) fn expand(&self, attr: MockableAttrArgs, item: Item) -> Result<TokenStream, Error> {
         let mockable_attr = MockableAttr::parse(attr)?;
         let item_trait = extract_item_trait(item)?;
         let trait_decl = TraitDecl::parse(item_trait.clone()).map_err(add_note_to_error)?;
+        validate_associated_consts(&trait_decl, &mockable_attr.const_attrs)?;
+        validate_method_names(&trait_decl)?;
 
         let generated_mock = generate_mock(
             &trait_decl,
             GenerateMockOptions {
                 custom_struct_ident: mockable_attr.name_attr.map(|attr| attr.ident),
                 force_static_lifetimes: mockable_attr.static_attr.is_some(),
+                default_returns: mockable_attr.default_returns_attr.is_some(),
+                nice: mockable_attr.nice_attr.is_some(),
+                sync: mockable_attr.sync_attr.is_some(),
+                associated_const_values: mockable_attr.const_attrs,
             },
         );
 
@@ -43,6 +52,80 @@ fn extract_item_trait(item: Item) -> Result<ItemTrait, Error> {
     }
 }
 
+/// Checks that every associated const of the trait has a value
+/// and that every const sub-attribute refers to an associated const.
+fn validate_associated_consts(
+    trait_decl: &TraitDecl,
+    const_attrs: &[ConstAttr],
+) -> Result<(), Error> {
+    let missing_values = trait_decl
+        .associated_consts
+        .iter()
+        .filter(|associated_const| {
+            associated_const.default.is_none()
+                && !const_attrs
+                    .iter()
+                    .any(|const_attr| const_attr.ident == associated_const.ident)
+        })
+        .map(|associated_const| {
+            associated_const_without_value_error(&associated_const.ident, associated_const.span)
+        });
+
+    let unknown_consts = const_attrs
+        .iter()
+        .filter(|const_attr| {
+            !trait_decl
+                .associated_consts
+                .iter()
+                .any(|associated_const| associated_const.ident == const_attr.ident)
+        })
+        .map(unknown_associated_const_error);
+
+    let errors: Vec<_> = missing_values.chain(unknown_consts).collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into_iter().collect())
+    }
+}
+
+/// Checks that no method of the trait has the same name as a member of the generated mock struct
+/// or as a method that the mock struct generates for another method of the trait.
+fn validate_method_names(trait_decl: &TraitDecl) -> Result<(), Error> {
+    let member_collisions = trait_decl
+        .methods
+        .iter()
+        .filter(|method_decl| {
+            MOCK_STRUCT_MEMBER_NAMES
+                .iter()
+                .any(|name| method_decl.ident == name)
+        })
+        .map(method_name_collides_with_mock_member_error);
+
+    let generated_method_collisions = trait_decl.methods.iter().flat_map(|method_decl| {
+        trait_decl
+            .methods
+            .iter()
+            .filter(move |other_method_decl| {
+                generated_method_idents(other_method_decl).contains(&method_decl.ident)
+            })
+            .map(move |other_method_decl| {
+                method_name_collides_with_generated_method_error(method_decl, other_method_decl)
+            })
+    });
+
+    let errors: Vec<_> = member_collisions
+        .chain(generated_method_collisions)
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.into_iter().collect())
+    }
+}
+
 fn add_note_to_error(error: Error) -> Error {
     error
         .diagnostics
@@ -61,3 +144,55 @@ fn only_traits_can_be_made_mockable_error(item: &Item) -> Error {
         .build()
         .into()
 }
+
+fn associated_const_without_value_error(ident: &Ident, span: Span) -> Error {
+    DiagnosticBuilder::error(
+        span,
+        "Associated consts without a default value are not supported",
+    )
+    .help(format!(
+        "Specify a value using #[{}(const {} = ...)]",
+        ATTR_NAME, ident
+    ))
+    .note_with_span(Span::call_site(), "Required for mockable traits")
+    .build()
+    .into()
+}
+
+fn unknown_associated_const_error(const_attr: &ConstAttr) -> Error {
+    let error_message = format!("The trait has no associated const `{}`", const_attr.ident);
+    DiagnosticBuilder::error(const_attr.ident.span(), error_message)
+        .build()
+        .into()
+}
+
+fn method_name_collides_with_mock_member_error(method_decl: &MethodDecl) -> Error {
+    let error_message = format!(
+        "Methods named `{}` are not supported, because the mock generates a member with the same \
+         name",
+        method_decl.ident
+    );
+    DiagnosticBuilder::error(method_decl.ident.span(), error_message)
+        .note_with_span(Span::call_site(), "Required for mockable traits")
+        .build()
+        .into()
+}
+
+fn method_name_collides_with_generated_method_error(
+    method_decl: &MethodDecl,
+    other_method_decl: &MethodDecl,
+) -> Error {
+    let error_message = format!(
+        "Methods named `{}` are not supported, because the mock generates a method with the same \
+         name for the method `{}`",
+        method_decl.ident, other_method_decl.ident
+    );
+    DiagnosticBuilder::error(method_decl.ident.span(), error_message)
+        .note_with_span(
+            other_method_decl.ident.span(),
+            "The colliding method is generated for this method",
+        )
+        .note_with_span(Span::call_site(), "Required for mockable traits")
+        .build()
+        .into()
+}
@@ -1,11 +1,18 @@
 use crate::diagnostic::DiagnosticBuilder;
 use crate::result::Result;
 use proc_macro2::Span;
+pub(crate) mod associated_const_decl;
+pub(crate) mod associated_type_decl;
+pub(crate) mod const_attr;
+pub(crate) mod default_returns_attr;
 pub(crate) mod method_decl;
 pub(crate) mod method_inputs;
 pub(crate) mod mockable_attr;
 pub(crate) mod name_attr;
+pub(crate) mod nice_attr;
+pub(crate) mod return_kind;
 pub(crate) mod static_attr;
+pub(crate) mod sync_attr;
 pub(crate) mod trait_decl;
 
 fn check_option_is_none<T>(value: &Option<T>, span: Span, error_message: &str) -> Result<()> {
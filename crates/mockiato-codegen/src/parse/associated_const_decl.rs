@@ -0,0 +1,33 @@
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, TraitItemConst, Type};
+
+/// An associated const of a mockable trait. (E.g. `const NAME: &'static str = "codec";`)
+///
+/// The mock uses the default value of the trait unless
+/// a value is given using `#[mockable(const NAME = ...)]`.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct AssociatedConstDecl {
+    /// The name of this associated const. (E.g. `NAME`)
+    pub(crate) ident: Ident,
+    /// The type of this associated const. (E.g. `&'static str`)
+    pub(crate) ty: Type,
+    /// The default value of this associated const. (E.g. `"codec"`)
+    pub(crate) default: Option<Expr>,
+    /// The [`Span`] of the entire associated const
+    pub(crate) span: Span,
+}
+
+impl AssociatedConstDecl {
+    pub(crate) fn parse(item: TraitItemConst) -> Self {
+        let span = item.span();
+
+        Self {
+            ident: item.ident,
+            ty: item.ty,
+            default: item.default.map(|(_, default)| default),
+            span,
+        }
+    }
+}
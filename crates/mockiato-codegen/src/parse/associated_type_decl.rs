@@ -0,0 +1,151 @@
+use crate::diagnostic::DiagnosticBuilder;
+use crate::result::Result;
+use proc_macro2::Span;
+use std::collections::HashSet;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit_mut::{visit_type_mut, VisitMut};
+use syn::{Ident, Path, QSelf, Token, TraitItemType, Type, TypeParam, TypeParamBound, TypePath};
+
+/// An associated type of a mockable trait. (E.g. `type Item: Debug;`)
+///
+/// The mock has a type parameter with the same name and bounds,
+/// which is used as the associated type.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct AssociatedTypeDecl {
+    /// The name of this associated type. (E.g. `Item`)
+    pub(crate) ident: Ident,
+    /// The bounds of this associated type. (E.g. `Debug`)
+    pub(crate) bounds: Punctuated<TypeParamBound, Token![+]>,
+    /// The [`Span`] of the entire associated type
+    pub(crate) span: Span,
+}
+
+impl AssociatedTypeDecl {
+    pub(crate) fn parse(item: TraitItemType) -> Result<Self> {
+        let span = item.span();
+
+        if !item.generics.params.is_empty() {
+            return Err(DiagnosticBuilder::error(
+                item.generics.span(),
+                "Generic associated types are not supported",
+            )
+            .build()
+            .into());
+        }
+
+        Ok(Self {
+            ident: item.ident,
+            bounds: item.bounds,
+            span,
+        })
+    }
+
+    /// Returns the type parameter of the mock that is used as this associated type.
+    pub(crate) fn type_param(&self) -> TypeParam {
+        let mut type_param = TypeParam::from(self.ident.clone());
+        type_param.bounds = self.bounds.clone();
+        type_param
+    }
+}
+
+/// Replaces `Self::Item` and `<Self as Trait>::Item` with `Item` for every associated type `Item`,
+/// so that the associated types refer to the type parameters of the mock.
+pub(crate) struct AssociatedTypeRewriter<'a> {
+    associated_types: &'a HashSet<Ident>,
+}
+
+impl<'a> AssociatedTypeRewriter<'a> {
+    pub(crate) fn new(associated_types: &'a HashSet<Ident>) -> Self {
+        Self { associated_types }
+    }
+
+    fn associated_type<'b>(&self, qself: Option<&QSelf>, path: &'b Path) -> Option<&'b Ident> {
+        let self_segment_count = match qself {
+            None => 1,
+            Some(qself) if is_self_type(&qself.ty) => qself.position,
+            Some(_) => return None,
+        };
+
+        let is_associated_type_path = path.leading_colon.is_none()
+            && path.segments.len() == self_segment_count + 1
+            && (qself.is_some() || path.segments[0].ident == "Self");
+
+        let segment = path.segments.iter().last()?;
+
+        if is_associated_type_path
+            && segment.arguments.is_empty()
+            && self.associated_types.contains(&segment.ident)
+        {
+            Some(&segment.ident)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> VisitMut for AssociatedTypeRewriter<'a> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        let replacement = match ty {
+            Type::Path(TypePath { qself, path }) => {
+                self.associated_type(qself.as_ref(), path).map(|ident| {
+                    Type::from(TypePath {
+                        qself: None,
+                        path: Path::from(ident.clone()),
+                    })
+                })
+            }
+            _ => None,
+        };
+
+        match replacement {
+            Some(replacement) => *ty = replacement,
+            None => visit_type_mut(self, ty),
+        }
+    }
+}
+
+fn is_self_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path.is_ident("Self"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+    use syn::parse_quote;
+
+    fn rewrite(mut ty: Type) -> String {
+        let associated_types = vec![parse_quote!(Item)].into_iter().collect();
+        AssociatedTypeRewriter::new(&associated_types).visit_type_mut(&mut ty);
+        quote!(#ty).to_string()
+    }
+
+    #[test]
+    fn associated_types_of_self_are_rewritten() {
+        assert_eq!("Option < Item >", rewrite(parse_quote!(Option<Self::Item>)));
+    }
+
+    #[test]
+    fn qualified_associated_types_of_self_are_rewritten() {
+        assert_eq!("Item", rewrite(parse_quote!(<Self as Iterator>::Item)));
+    }
+
+    #[test]
+    fn associated_types_of_other_types_are_kept() {
+        assert_eq!("T :: Item", rewrite(parse_quote!(T::Item)));
+        assert_eq!(
+            "< T as Iterator > :: Item",
+            rewrite(parse_quote!(<T as Iterator>::Item))
+        );
+    }
+
+    #[test]
+    fn unknown_associated_types_are_kept() {
+        assert_eq!("Self :: Output", rewrite(parse_quote!(Self::Output)));
+    }
+}
@@ -0,0 +1,21 @@
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Ident, Token};
+
+/// The const sub-attribute. Example: `#[mockable(const NAME = "fake")]`.
+/// Sets the value of an associated const of the mock.
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct ConstAttr {
+    pub(crate) ident: Ident,
+    pub(crate) value: Expr,
+}
+
+impl Parse for ConstAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        input.parse::<Token![const]>()?;
+        let ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+
+        Ok(Self { ident, value })
+    }
+}
@@ -1,6 +1,7 @@
 use super::check_option_is_none;
 use crate::diagnostic::DiagnosticBuilder;
 use crate::parse::method_inputs::MethodInputs;
+use crate::parse::return_kind::ReturnKind;
 use crate::result::{merge_results, Error, Result};
 use crate::syn_ext::PathExt;
 use proc_macro2::Span;
@@ -8,8 +9,8 @@ use std::collections::HashSet;
 use syn::spanned::Spanned;
 use syn::visit::{visit_type, Visit};
 use syn::{
-    Attribute, FnDecl, GenericParam, Generics, Ident, MethodSig, Path, ReturnType, Token,
-    TraitItem, TraitItemMethod, Type, TypePath,
+    Attribute, FnDecl, GenericParam, Generics, Ident, MethodSig, Path, PredicateType, ReturnType,
+    Token, TraitItem, TraitItemMethod, Type, TypeParam, TypeParamBound, TypePath, WherePredicate,
 };
 
 /// Holds everything required to generate a mock struct
@@ -31,6 +32,8 @@ pub(crate) struct MethodDecl {
     pub(crate) inputs: MethodInputs,
     /// Return type of this method.
     pub(crate) output: ReturnType,
+    /// Whether this method returns its value directly or as a future.
+    pub(crate) return_kind: ReturnKind,
 }
 
 impl MethodDecl {
@@ -64,7 +67,12 @@ impl MethodDecl {
             ..
         } = signature;
 
-        validate_usage_of_generic_types(&decl, generic_types_on_trait)?;
+        let generic_types = generic_types_on_trait
+            .iter()
+            .chain(decl.generics.type_params().map(|param| &param.ident))
+            .cloned()
+            .collect();
+        validate_usage_of_generic_types(&decl, &generic_types)?;
 
         let FnDecl {
             generics,
@@ -76,7 +84,6 @@ impl MethodDecl {
         validate_generic_type_parameters(&generics)?;
 
         check_option_is_none(&constness, span, "`const` methods are not supported")?;
-        check_option_is_none(&asyncness, span, "`async` methods are not supported")?;
 
         Ok(Self {
             attrs,
@@ -85,15 +92,21 @@ impl MethodDecl {
             generics,
             span,
             inputs: MethodInputs::parse(inputs)?,
+            return_kind: ReturnKind::parse(asyncness, &output)?,
             output,
         })
     }
+
+    /// Whether this method has type parameters.
+    pub(crate) fn is_generic(&self) -> bool {
+        self.generics.type_params().next().is_some()
+    }
 }
 
 fn invalid_trait_item_error(trait_item: &TraitItem) -> Error {
     DiagnosticBuilder::error(
         trait_item.span(),
-        "Traits are only allowed to contain methods",
+        "Traits are only allowed to contain methods, associated types and associated consts",
     )
     .build()
     .into()
@@ -105,14 +118,54 @@ fn validate_generic_type_parameters(generics: &Generics) -> Result<()> {
         .iter()
         .map(|generic_param| match generic_param {
             GenericParam::Lifetime(_) => Ok(()),
-            generic_param => Err(invalid_generic_param(generic_param)),
+            GenericParam::Type(type_param) => validate_type_param_is_static(type_param, generics),
+            GenericParam::Const(_) => Err(invalid_generic_param(generic_param)),
         });
 
     merge_results(results).map(|_| ())
 }
 
+/// The instantiations of generic methods are identified by the [`TypeId`]
+/// of their type arguments, which requires them to be `'static`.
+///
+/// [`TypeId`]: std::any::TypeId
+fn validate_type_param_is_static(type_param: &TypeParam, generics: &Generics) -> Result<()> {
+    let where_clause_bounds = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .filter_map(|predicate| match predicate {
+            WherePredicate::Type(PredicateType {
+                bounded_ty: Type::Path(TypePath { qself: None, path }),
+                bounds,
+                ..
+            }) if path.is_ident(type_param.ident.clone()) => Some(bounds.iter()),
+            _ => None,
+        })
+        .flatten();
+
+    let is_static = type_param
+        .bounds
+        .iter()
+        .chain(where_clause_bounds)
+        .any(|bound| match bound {
+            TypeParamBound::Lifetime(lifetime) => lifetime.ident == "static",
+            TypeParamBound::Trait(_) => false,
+        });
+
+    if is_static {
+        Ok(())
+    } else {
+        let error_message = "Type parameters of methods must be `'static`";
+        Err(DiagnosticBuilder::error(type_param.span(), error_message)
+            .help(format!("Add the bound `{}: 'static`", type_param.ident))
+            .build()
+            .into())
+    }
+}
+
 fn invalid_generic_param(generic_param: &GenericParam) -> Error {
-    let error_message = "Only lifetimes are supported as generic parameters on methods";
+    let error_message = "Const generics are not supported on methods";
     DiagnosticBuilder::error(generic_param.span(), error_message)
         .build()
         .into()
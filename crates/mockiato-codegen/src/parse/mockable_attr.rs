@@ -1,12 +1,47 @@
+use super::const_attr::ConstAttr;
+use super::default_returns_attr::DefaultReturnsAttr;
 use super::name_attr::NameAttr;
+use super::nice_attr::NiceAttr;
 use super::static_attr::StaticAttr;
+use super::sync_attr::SyncAttr;
 use crate::constant::{
-    ATTR_NAME, MOCK_STRUCT_NAME_ATTR_PARAM_NAME, STATIC_REFERENCES_ATTR_PARAM_NAME,
+    ATTR_NAME, DEFAULT_RETURNS_ATTR_PARAM_NAME, MOCK_STRUCT_NAME_ATTR_PARAM_NAME,
+    NICE_ATTR_PARAM_NAME, STATIC_REFERENCES_ATTR_PARAM_NAME, SYNC_ATTR_PARAM_NAME,
 };
 use crate::diagnostic::DiagnosticBuilder;
 use crate::result::{merge_results, Error, Result};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{AttributeArgs, Lit, Meta, NestedMeta};
+use syn::{AttributeArgs, Lit, Meta, NestedMeta, Token};
+
+/// The arguments of the `#[mockable]` attribute.
+/// Unlike [`AttributeArgs`], this also accepts const sub-attributes. Example: `const NAME = "fake"`
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) struct MockableAttrArgs(Vec<MockableAttrArg>);
+
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+enum MockableAttrArg {
+    Meta(NestedMeta),
+    Const(ConstAttr),
+}
+
+impl Parse for MockableAttrArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let args = Punctuated::<MockableAttrArg, Token![,]>::parse_terminated(input)?;
+        Ok(MockableAttrArgs(args.into_iter().collect()))
+    }
+}
+
+impl Parse for MockableAttrArg {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if input.peek(Token![const]) {
+            input.parse().map(MockableAttrArg::Const)
+        } else {
+            input.parse().map(MockableAttrArg::Meta)
+        }
+    }
+}
 
 /// The `#[mockable]` attribute, which is placed on a trait.
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
@@ -17,14 +52,48 @@ pub(crate) struct MockableAttr {
     /// The static sub-attribute. Example: `#[mockable(static)]`.
     /// Enforces that only static lifetimes are used within the mock.
     pub(crate) static_attr: Option<StaticAttr>,
+    /// The default returns sub-attribute. Example: `#[mockable(default_returns)]`.
+    /// Expected calls without a return value return [`Default::default`]
+    /// when the return type implements [`Default`].
+    pub(crate) default_returns_attr: Option<DefaultReturnsAttr>,
+    /// The nice sub-attribute. Example: `#[mockable(nice)]`.
+    /// Unexpected calls return a default value instead of panicking.
+    pub(crate) nice_attr: Option<NiceAttr>,
+    /// The sync sub-attribute. Example: `#[mockable(sync)]`.
+    /// Argument matchers must be [`Send`] and [`Sync`], so that the mock can be shared between threads.
+    pub(crate) sync_attr: Option<SyncAttr>,
+    /// The const sub-attributes. Example: `#[mockable(const NAME = "fake")]`.
+    /// Sets the values of the associated consts of the mock.
+    pub(crate) const_attrs: Vec<ConstAttr>,
 }
 
 impl MockableAttr {
-    pub(crate) fn parse(args: AttributeArgs) -> Result<Self> {
-        let meta_items = get_meta_items(args)?;
+    pub(crate) fn parse(MockableAttrArgs(args): MockableAttrArgs) -> Result<Self> {
+        let mut const_attrs: Vec<ConstAttr> = Vec::new();
+        let mut nested_meta_items = Vec::new();
+
+        for arg in args {
+            match arg {
+                MockableAttrArg::Meta(nested_meta) => nested_meta_items.push(nested_meta),
+                MockableAttrArg::Const(const_attr) => {
+                    if const_attrs
+                        .iter()
+                        .any(|existing| existing.ident == const_attr.ident)
+                    {
+                        return Err(const_specified_more_than_once_error(&const_attr));
+                    }
+                    const_attrs.push(const_attr);
+                }
+            }
+        }
+
+        let meta_items = get_meta_items(nested_meta_items)?;
 
         let mut name_attr = None;
         let mut static_attr = None;
+        let mut default_returns_attr = None;
+        let mut nice_attr = None;
+        let mut sync_attr = None;
 
         for item in meta_items {
             let item_name = item.name();
@@ -39,6 +108,21 @@ impl MockableAttr {
                     return Err(static_references_specified_more_than_once_error(&item));
                 }
                 static_attr = Some(StaticAttr::parse(item)?);
+            } else if item_name == DEFAULT_RETURNS_ATTR_PARAM_NAME {
+                if default_returns_attr.is_some() {
+                    return Err(default_returns_specified_more_than_once_error(&item));
+                }
+                default_returns_attr = Some(DefaultReturnsAttr::parse(item)?);
+            } else if item_name == NICE_ATTR_PARAM_NAME {
+                if nice_attr.is_some() {
+                    return Err(nice_specified_more_than_once_error(&item));
+                }
+                nice_attr = Some(NiceAttr::parse(item)?);
+            } else if item_name == SYNC_ATTR_PARAM_NAME {
+                if sync_attr.is_some() {
+                    return Err(sync_specified_more_than_once_error(&item));
+                }
+                sync_attr = Some(SyncAttr::parse(item)?);
             } else {
                 return Err(attribute_property_not_supported_error(&item));
             }
@@ -47,6 +131,10 @@ impl MockableAttr {
         Ok(Self {
             name_attr,
             static_attr,
+            default_returns_attr,
+            nice_attr,
+            sync_attr,
+            const_attrs,
         })
     }
 }
@@ -73,10 +161,29 @@ fn static_references_specified_more_than_once_error(meta_item: &Meta) -> Error {
     parameter_specified_more_than_once_error(STATIC_REFERENCES_ATTR_PARAM_NAME, meta_item)
 }
 
+fn default_returns_specified_more_than_once_error(meta_item: &Meta) -> Error {
+    parameter_specified_more_than_once_error(DEFAULT_RETURNS_ATTR_PARAM_NAME, meta_item)
+}
+
+fn nice_specified_more_than_once_error(meta_item: &Meta) -> Error {
+    parameter_specified_more_than_once_error(NICE_ATTR_PARAM_NAME, meta_item)
+}
+
+fn sync_specified_more_than_once_error(meta_item: &Meta) -> Error {
+    parameter_specified_more_than_once_error(SYNC_ATTR_PARAM_NAME, meta_item)
+}
+
 fn name_specified_more_than_once_error(meta_item: &Meta) -> Error {
     parameter_specified_more_than_once_error(MOCK_STRUCT_NAME_ATTR_PARAM_NAME, meta_item)
 }
 
+fn const_specified_more_than_once_error(const_attr: &ConstAttr) -> Error {
+    let error_message = format!("`const {}` is specified more than once.", const_attr.ident);
+    DiagnosticBuilder::error(const_attr.ident.span(), error_message)
+        .build()
+        .into()
+}
+
 fn parameter_specified_more_than_once_error(name: &str, meta_item: &Meta) -> Error {
     let error_message = format!("`{}` is specified more than once.", name);
     DiagnosticBuilder::error(meta_item.span(), error_message)
@@ -0,0 +1,169 @@
+use crate::diagnostic::DiagnosticBuilder;
+use crate::result::Result;
+use syn::spanned::Spanned;
+use syn::{
+    GenericArgument, Path, PathArguments, ReturnType, Token, Type, TypeImplTrait, TypeParamBound,
+    TypePath, TypeTraitObject,
+};
+
+/// Describes how a method returns its value.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug-impls", derive(Debug))]
+pub(crate) enum ReturnKind {
+    /// The method returns its value directly.
+    Value,
+    /// The method is declared as `async fn`.
+    Async,
+    /// The method returns `impl Future<Output = T>`. Holds the output type `T`.
+    ImplFuture(Type),
+    /// The method returns `Pin<Box<dyn Future<Output = T>>>`, as generated by `#[async_trait]`.
+    /// Holds the output type `T`.
+    BoxedFuture(Type),
+}
+
+impl ReturnKind {
+    pub(crate) fn parse(asyncness: Option<Token![async]>, output: &ReturnType) -> Result<Self> {
+        if asyncness.is_some() {
+            return Ok(ReturnKind::Async);
+        }
+
+        let ty = match output {
+            ReturnType::Default => return Ok(ReturnKind::Value),
+            ReturnType::Type(_, ty) => ty,
+        };
+
+        match ty.as_ref() {
+            Type::ImplTrait(TypeImplTrait { bounds, .. }) => future_output(bounds.iter())
+                .map(ReturnKind::ImplFuture)
+                .ok_or_else(|| {
+                    DiagnosticBuilder::error(
+                        ty.span(),
+                        "`impl Trait` is only supported in return position for `impl Future`",
+                    )
+                    .build()
+                    .into()
+                }),
+            Type::Path(TypePath { path, .. }) => Ok(boxed_future_output(path)
+                .map(ReturnKind::BoxedFuture)
+                .unwrap_or(ReturnKind::Value)),
+            _ => Ok(ReturnKind::Value),
+        }
+    }
+}
+
+/// Returns `T` if the path is `Pin<Box<dyn Future<Output = T>>>`.
+fn boxed_future_output(path: &Path) -> Option<Type> {
+    let boxed = single_type_argument(path, "Pin")?;
+
+    let trait_object = match boxed {
+        Type::Path(TypePath { path, .. }) => single_type_argument(path, "Box")?,
+        _ => return None,
+    };
+
+    match trait_object {
+        Type::TraitObject(TypeTraitObject { bounds, .. }) => future_output(bounds.iter()),
+        _ => None,
+    }
+}
+
+/// Returns `T` if the last segment of the path is the given identifier with a single type argument `T`.
+fn single_type_argument<'a>(path: &'a Path, ident: &str) -> Option<&'a Type> {
+    let segment = path.segments.iter().last()?;
+
+    if segment.ident != ident {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.iter().next()? {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns `T` if one of the bounds is `Future<Output = T>`.
+fn future_output<'a>(mut bounds: impl Iterator<Item = &'a TypeParamBound>) -> Option<Type> {
+    bounds.find_map(|bound| {
+        let path = match bound {
+            TypeParamBound::Trait(trait_bound) => &trait_bound.path,
+            TypeParamBound::Lifetime(_) => return None,
+        };
+        let segment = path.segments.iter().last()?;
+
+        if segment.ident != "Future" {
+            return None;
+        }
+
+        match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => {
+                arguments.args.iter().find_map(|argument| match argument {
+                    GenericArgument::Binding(binding) if binding.ident == "Output" => {
+                        Some(binding.ty.clone())
+                    }
+                    _ => None,
+                })
+            }
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+    use syn::parse_quote;
+
+    #[test]
+    fn async_methods_are_detected() {
+        let kind = ReturnKind::parse(Some(parse_quote!(async)), &parse_quote!(-> String)).unwrap();
+
+        match kind {
+            ReturnKind::Async => {}
+            _ => panic!("expected async method"),
+        }
+    }
+
+    #[test]
+    fn output_of_impl_future_is_extracted() {
+        let output = parse_quote!(-> impl std::future::Future<Output = String> + Send);
+
+        match ReturnKind::parse(None, &output).unwrap() {
+            ReturnKind::ImplFuture(ty) => assert_eq!("String", quote!(#ty).to_string()),
+            _ => panic!("expected impl future"),
+        }
+    }
+
+    #[test]
+    fn output_of_boxed_future_is_extracted() {
+        let output = parse_quote!(
+            -> ::core::pin::Pin<Box<dyn ::core::future::Future<Output = u32> + Send + 'async_trait>>
+        );
+
+        match ReturnKind::parse(None, &output).unwrap() {
+            ReturnKind::BoxedFuture(ty) => assert_eq!("u32", quote!(#ty).to_string()),
+            _ => panic!("expected boxed future"),
+        }
+    }
+
+    #[test]
+    fn other_return_types_are_values() {
+        let output = parse_quote!(-> Pin<Box<u32>>);
+
+        match ReturnKind::parse(None, &output).unwrap() {
+            ReturnKind::Value => {}
+            _ => panic!("expected value"),
+        }
+    }
+
+    #[test]
+    fn other_impl_traits_are_rejected() {
+        let output = parse_quote!(-> impl Iterator<Item = u32>);
+
+        assert!(ReturnKind::parse(None, &output).is_err());
+    }
+}
@@ -1,12 +1,18 @@
 use super::check_option_is_none;
 use crate::diagnostic::DiagnosticBuilder;
+use crate::parse::associated_const_decl::AssociatedConstDecl;
+use crate::parse::associated_type_decl::{AssociatedTypeDecl, AssociatedTypeRewriter};
 use crate::parse::method_decl::MethodDecl;
 use crate::result::{merge_results, Error, Result};
 use proc_macro2::Span;
 use std::collections::HashSet;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{GenericParam, Generics, Ident, ItemTrait, Token, TypeParamBound, Visibility};
+use syn::visit_mut::VisitMut;
+use syn::{
+    Attribute, GenericParam, Generics, Ident, ItemTrait, Token, TraitItem, TypeParamBound,
+    Visibility,
+};
 
 #[derive(Clone)]
 #[cfg_attr(feature = "debug-impls", derive(Debug))]
@@ -18,12 +24,19 @@ pub(crate) struct TraitDecl {
     pub(crate) unsafety: Option<Token![unsafe]>,
     pub(crate) supertraits: Punctuated<TypeParamBound, Token![+]>,
     pub(crate) methods: Vec<MethodDecl>,
+    /// The associated types of this trait, which are type parameters of the mock.
+    pub(crate) associated_types: Vec<AssociatedTypeDecl>,
+    /// The associated consts of this trait.
+    pub(crate) associated_consts: Vec<AssociatedConstDecl>,
+    /// The `#[async_trait]` attribute of this trait, which needs to be applied to the mock's impl as well.
+    pub(crate) async_trait_attr: Option<Attribute>,
 }
 
 impl TraitDecl {
     pub(crate) fn parse(item: ItemTrait) -> Result<Self> {
         let span = item.span();
         let ItemTrait {
+            attrs,
             auto_token,
             unsafety,
             generics,
@@ -37,37 +50,89 @@ impl TraitDecl {
         check_option_is_none(&auto_token, span, "Auto traits are not supported")?;
         validate_generic_type_parameters(&generics)?;
 
-        let generic_types = collect_generic_type_idents(&generics);
-        let methods = items
-            .into_iter()
-            .map(move |method| MethodDecl::parse(method, &generic_types));
+        let (associated_types, mut associated_consts, items) = parse_associated_items(items)?;
+        let associated_type_idents: HashSet<_> = associated_types
+            .iter()
+            .map(|associated_type| associated_type.ident.clone())
+            .collect();
+        let mut rewriter = AssociatedTypeRewriter::new(&associated_type_idents);
+
+        let mut generics = generics;
+        rewriter.visit_generics_mut(&mut generics);
+
+        let mut generic_types = collect_generic_type_idents(&generics);
+        generic_types.extend(associated_type_idents.iter().cloned());
+
+        for associated_const in &mut associated_consts {
+            rewriter.visit_type_mut(&mut associated_const.ty);
+        }
+
+        let methods = items.into_iter().map(|mut item| {
+            rewriter.visit_trait_item_mut(&mut item);
+            MethodDecl::parse(item, &generic_types)
+        });
+        let methods = merge_results(methods)?.collect();
 
         Ok(TraitDecl {
             visibility,
             ident,
             span,
             unsafety,
-            generics: generics.clone(),
-            supertraits: supertraits.clone(),
-            methods: merge_results(methods)?.collect(),
+            generics,
+            supertraits,
+            methods,
+            associated_types,
+            associated_consts,
+            async_trait_attr: attrs.into_iter().find(is_async_trait_attr),
         })
     }
 }
 
+/// Separates the associated types and consts from the remaining items of the trait.
+fn parse_associated_items(
+    items: Vec<TraitItem>,
+) -> Result<(
+    Vec<AssociatedTypeDecl>,
+    Vec<AssociatedConstDecl>,
+    Vec<TraitItem>,
+)> {
+    let mut associated_types = Vec::new();
+    let mut associated_consts = Vec::new();
+    let mut remaining_items = Vec::new();
+
+    for item in items {
+        match item {
+            TraitItem::Type(item) => associated_types.push(AssociatedTypeDecl::parse(item)),
+            TraitItem::Const(item) => associated_consts.push(AssociatedConstDecl::parse(item)),
+            item => remaining_items.push(item),
+        }
+    }
+
+    Ok((
+        merge_results(associated_types.into_iter())?.collect(),
+        associated_consts,
+        remaining_items,
+    ))
+}
+
+fn is_async_trait_attr(attr: &Attribute) -> bool {
+    attr.path
+        .segments
+        .iter()
+        .last()
+        .map_or(false, |segment| segment.ident == "async_trait")
+}
+
 fn validate_generic_type_parameters(generics: &Generics) -> Result<()> {
     let results = generics
         .params
         .iter()
         .map(|generic_param| match generic_param {
-            GenericParam::Type(_) => Ok(()),
+            GenericParam::Type(_) | GenericParam::Const(_) => Ok(()),
             GenericParam::Lifetime(_) => Err(invalid_generic_param_error(
                 generic_param,
                 "Lifetimes are not supported on mockable traits",
             )),
-            GenericParam::Const(_) => Err(invalid_generic_param_error(
-                generic_param,
-                "Const generics are not supported on mockable traits",
-            )),
         });
 
     merge_results(results).map(|_| ())
@@ -1,7 +1,14 @@
 use rustc_version::{version_meta, Channel};
 
 fn main() {
-    if let Channel::Nightly = version_meta().unwrap().channel {
+    let version_meta = version_meta().unwrap();
+
+    if let Channel::Nightly = version_meta.channel {
         println!("cargo:rustc-cfg=rustc_is_nightly");
     }
+
+    // Const generics with integral types are stable since rust 1.51
+    if (version_meta.semver.major, version_meta.semver.minor) >= (1, 51) {
+        println!("cargo:rustc-cfg=rustc_has_min_const_generics");
+    }
 }
@@ -4,3 +4,10 @@ fn ui_tests() {
     let test_cases = trybuild::TestCases::new();
     test_cases.compile_fail("tests/ui/*.rs");
 }
+
+#[cfg(all(rustc_is_nightly, rustc_has_min_const_generics))]
+#[test]
+fn const_generics_ui_tests() {
+    let test_cases = trybuild::TestCases::new();
+    test_cases.compile_fail("tests/ui/const_generics/*.rs");
+}
@@ -0,0 +1,13 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Codec {
+    const NAME: &'static str;
+}
+
+#[mockable(const VERSION = 2)]
+trait Versioned {
+    const NAME: &'static str = "versioned";
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Resizable<const N: usize> {
+    fn resize<const M: usize>(&self, bytes: [u8; N]) -> [u8; M];
+}
+
+#[mockable]
+trait Buffer<const N: usize> {
+    fn fill(&self, bytes: [u8; N]) -> [u8; N];
+}
+
+fn main() {
+    let mut buffer = BufferMock::<4>::new();
+    buffer.expect_fill(|arg| arg.any()).returns([0; 4]);
+    buffer.fill([1, 2, 3, 4]);
+}
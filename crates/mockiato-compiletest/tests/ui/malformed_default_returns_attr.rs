@@ -0,0 +1,9 @@
+use mockiato::mockable;
+
+#[mockable(default_returns = 1)]
+trait TraitOne {}
+
+#[mockable(default_returns, default_returns)]
+trait TraitTwo {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use mockiato::mockable;
+
+#[mockable(nice = 1)]
+trait TraitOne {}
+
+#[mockable(nice, nice)]
+trait TraitTwo {}
+
+fn main() {}
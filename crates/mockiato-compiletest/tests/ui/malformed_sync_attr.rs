@@ -0,0 +1,9 @@
+use mockiato::mockable;
+
+#[mockable(sync = 1)]
+trait TraitOne {}
+
+#[mockable(sync, sync)]
+trait TraitTwo {}
+
+fn main() {}
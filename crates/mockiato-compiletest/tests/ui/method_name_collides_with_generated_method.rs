@@ -0,0 +1,12 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Repository {
+    fn load(&self);
+
+    fn expect_load(&self);
+
+    fn wait_for_load(&self);
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Repository {
+    fn checkpoint(&self);
+
+    fn disarmed(&self) -> bool;
+}
+
+fn main() {}
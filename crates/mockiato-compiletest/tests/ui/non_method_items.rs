@@ -7,10 +7,10 @@ macro_rules! macro_in_trait {
 
 #[mockable]
 trait Foo {
-    const BAR: usize;
-
     type Baz;
 
+    const BAR: usize = 1;
+
     macro_in_trait!();
 }
 
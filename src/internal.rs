@@ -3,18 +3,47 @@
 //! It may change at any time. Do not use this module directly.
 //!
 
+pub use self::argument_capture::{
+    ArgumentCaptureSelector, CaptureArguments, CapturedArgument, SelectCaptureArgument,
+    SelectNoCaptureArgument, SelectNoRestoreOwnedArgument, SelectRestoreOwnedArgument,
+    SyncCapturedArgument,
+};
 pub use self::arguments::Arguments;
-pub use self::fmt::MaybeDebugWrapper;
-pub use self::matcher::{ArgumentMatcher, ArgumentsMatcher};
+pub use self::call_verification::CallVerification;
+pub use self::default_return_value::{
+    DefaultReturnValueFn, DefaultReturnValueSelector, SelectDefaultReturnValue,
+    SelectNoDefaultReturnValue,
+};
+pub use self::fmt::{
+    DebugFn, DebugFnSelector, DebugWith, MaybeDebugWrapper, SelectDebugFn, SelectFallbackDebugFn,
+};
+pub use self::generic_method::{ErasedMethod, ErasedMethodObject, GenericMethod, SyncErasedMethod};
+pub use self::matcher::{ArgumentMatcher, ArgumentMismatch, ArgumentsMatcher, Mismatch};
 pub use self::method::Method;
 pub use self::method_call::MethodCallBuilder;
+pub use self::mock_future::MockFuture;
 
+pub(crate) mod ambiguity_resolution;
 pub(crate) mod argument;
+mod argument_capture;
 mod arguments;
-pub(crate) mod default_return_value;
+mod call_number;
+mod call_verification;
+mod default_return_value;
+pub(crate) mod expectation;
 pub(crate) mod expected_calls;
 mod fmt;
+mod generic_method;
+pub(crate) mod grammar;
+mod location;
+mod lock;
 pub(crate) mod matcher;
 mod method;
 mod method_call;
+mod mock_future;
+pub(crate) mod recorded_call;
 mod return_value;
+pub(crate) mod sequence;
+pub(crate) mod states;
+pub(crate) mod strictness;
+pub(crate) mod verification_report;
@@ -0,0 +1,52 @@
+/// Decides which expected call is used when a call matches more than one expected call.
+///
+/// The strategy can be configured for all methods of a mock using `set_ambiguity_resolution`
+/// or for a single method using `set_<method_name>_ambiguity_resolution`.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, AmbiguityResolution};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait UserRepository {
+///     fn get(&self, name: &str) -> Option<u32>;
+/// }
+///
+/// let mut repository = UserRepositoryMock::new();
+/// repository.set_get_ambiguity_resolution(AmbiguityResolution::MostSpecific);
+///
+/// repository
+///     .expect_get(|arg| arg.any())
+///     .times(..)
+///     .returns(None);
+/// repository
+///     .expect_get(|arg| arg.partial_eq("admin"))
+///     .returns(Some(0));
+///
+/// assert_eq!(Some(0), repository.get("admin"));
+/// assert_eq!(None, repository.get("guest"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmbiguityResolution {
+    /// Calls matching more than one expected call panic. This is the default.
+    Strict,
+    /// The expected call that was defined last is used,
+    /// even if it has already been called the maximum number of times.
+    MostRecentlyDefined,
+    /// The first expected call that has not yet been called
+    /// the maximum number of times is used.
+    FirstUnsaturated,
+    /// The expected call with the most arguments that are not matched using `any` is used.
+    /// Expected calls that have already been called the maximum number of times are skipped.
+    /// Calls matching more than one of the most specific expected calls panic.
+    MostSpecific,
+}
+
+impl Default for AmbiguityResolution {
+    fn default() -> Self {
+        AmbiguityResolution::Strict
+    }
+}
@@ -0,0 +1,227 @@
+use nameof::name_of;
+use std::any::Any;
+use std::borrow::Borrow;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+/// An owned copy of an argument, recorded so that calls can be verified after they were made.
+pub type CapturedArgument = Box<dyn Any>;
+
+/// An owned copy of an argument of a `sync` mock, which can be shared between threads.
+pub type SyncCapturedArgument = Box<dyn Any + Send + Sync>;
+
+/// Defines how the arguments of a method are captured.
+/// Mocks generated with `#[mockable(sync)]` use [`SyncCapturedArgument`],
+/// all other mocks use [`CapturedArgument`].
+pub trait CaptureArguments {
+    type CapturedArgument: Debug;
+}
+
+/// An owned copy of an argument that can be downcast to its original type.
+pub trait DowncastCapturedArgument {
+    fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Any;
+}
+
+impl DowncastCapturedArgument for CapturedArgument {
+    fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        (**self).downcast_ref()
+    }
+}
+
+impl DowncastCapturedArgument for SyncCapturedArgument {
+    fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        (**self).downcast_ref()
+    }
+}
+
+/// Selects how an argument is captured and restored without requiring specialization.
+///
+/// `(&&ArgumentCaptureSelector::<T, C>::new()).capture(value)` resolves to
+/// [`SelectCaptureArgument`] if `T: ToOwned` and the owned value can be stored in `C`,
+/// i.e. is [`Send`] and [`Sync`] if `C` is [`SyncCapturedArgument`],
+/// and falls back to [`SelectNoCaptureArgument`] otherwise.
+/// Owned arguments are restored using [`SelectRestoreOwnedArgument`] if `T: Clone`.
+pub struct ArgumentCaptureSelector<T, C>(PhantomData<T>, PhantomData<C>)
+where
+    T: ?Sized;
+
+impl<T, C> ArgumentCaptureSelector<T, C>
+where
+    T: ?Sized,
+{
+    pub fn new() -> Self {
+        ArgumentCaptureSelector(PhantomData, PhantomData)
+    }
+}
+
+pub trait SelectCaptureArgument<T, C>
+where
+    T: ?Sized,
+{
+    fn capture(&self, value: &T) -> Option<C>;
+
+    fn restore_borrowed<'a>(&self, captured: &'a C) -> Option<&'a T>;
+}
+
+impl<T> SelectCaptureArgument<T, CapturedArgument> for &ArgumentCaptureSelector<T, CapturedArgument>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: 'static,
+{
+    fn capture(&self, value: &T) -> Option<CapturedArgument> {
+        Some(Box::new(value.to_owned()))
+    }
+
+    fn restore_borrowed<'a>(&self, captured: &'a CapturedArgument) -> Option<&'a T> {
+        captured.downcast_ref::<T::Owned>().map(Borrow::borrow)
+    }
+}
+
+impl<T> SelectCaptureArgument<T, SyncCapturedArgument>
+    for &ArgumentCaptureSelector<T, SyncCapturedArgument>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: Send + Sync + 'static,
+{
+    fn capture(&self, value: &T) -> Option<SyncCapturedArgument> {
+        Some(Box::new(value.to_owned()))
+    }
+
+    fn restore_borrowed<'a>(&self, captured: &'a SyncCapturedArgument) -> Option<&'a T> {
+        captured.downcast_ref::<T::Owned>().map(Borrow::borrow)
+    }
+}
+
+pub trait SelectNoCaptureArgument<T, C>
+where
+    T: ?Sized,
+{
+    fn capture(&self, value: &T) -> Option<C>;
+
+    fn restore_borrowed<'a>(&self, captured: &'a C) -> Option<&'a T>;
+}
+
+impl<T, C> SelectNoCaptureArgument<T, C> for ArgumentCaptureSelector<T, C>
+where
+    T: ?Sized,
+{
+    fn capture(&self, _value: &T) -> Option<C> {
+        None
+    }
+
+    fn restore_borrowed<'a>(&self, _captured: &'a C) -> Option<&'a T> {
+        None
+    }
+}
+
+pub trait SelectRestoreOwnedArgument<T, C> {
+    fn restore_owned(&self, captured: &C) -> Option<T>;
+}
+
+impl<T, C> SelectRestoreOwnedArgument<T, C> for &ArgumentCaptureSelector<T, C>
+where
+    T: Clone + 'static,
+    C: DowncastCapturedArgument,
+{
+    fn restore_owned(&self, captured: &C) -> Option<T> {
+        captured.downcast_ref::<T>().cloned()
+    }
+}
+
+pub trait SelectNoRestoreOwnedArgument<T, C> {
+    fn restore_owned(&self, captured: &C) -> Option<T>;
+}
+
+impl<T, C> SelectNoRestoreOwnedArgument<T, C> for ArgumentCaptureSelector<T, C> {
+    fn restore_owned(&self, _captured: &C) -> Option<T> {
+        None
+    }
+}
+
+impl<T, C> Default for ArgumentCaptureSelector<T, C>
+where
+    T: ?Sized,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C> Debug for ArgumentCaptureSelector<T, C>
+where
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type ArgumentCaptureSelector<T, C>))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::rc::Rc;
+
+    struct NotClone;
+
+    #[test]
+    fn borrowed_arguments_are_captured_as_owned_values() {
+        let captured = (&&ArgumentCaptureSelector::<str, CapturedArgument>::new())
+            .capture("foo")
+            .unwrap();
+
+        assert_eq!(
+            Some("foo"),
+            (&&ArgumentCaptureSelector::<str, CapturedArgument>::new()).restore_borrowed(&captured)
+        );
+    }
+
+    #[test]
+    fn owned_arguments_are_restored_using_clone() {
+        let captured = (&&ArgumentCaptureSelector::<u32, CapturedArgument>::new())
+            .capture(&42)
+            .unwrap();
+
+        assert_eq!(
+            Some(42),
+            (&&ArgumentCaptureSelector::<u32, CapturedArgument>::new()).restore_owned(&captured)
+        );
+    }
+
+    #[test]
+    fn arguments_not_implementing_clone_are_not_captured() {
+        let captured =
+            (&&ArgumentCaptureSelector::<NotClone, CapturedArgument>::new()).capture(&NotClone);
+
+        assert!(captured.is_none());
+    }
+
+    #[test]
+    fn arguments_not_implementing_send_are_captured_for_mocks_that_are_not_sync() {
+        let captured = (&&ArgumentCaptureSelector::<Rc<u32>, CapturedArgument>::new())
+            .capture(&Rc::new(42))
+            .unwrap();
+
+        assert_eq!(
+            Some(Rc::new(42)),
+            (&&ArgumentCaptureSelector::<Rc<u32>, CapturedArgument>::new())
+                .restore_owned(&captured)
+        );
+    }
+
+    #[test]
+    fn arguments_not_implementing_send_are_not_captured_for_sync_mocks() {
+        let captured = (&&ArgumentCaptureSelector::<Rc<u32>, SyncCapturedArgument>::new())
+            .capture(&Rc::new(42));
+
+        assert!(captured.is_none());
+    }
+}
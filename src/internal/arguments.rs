@@ -1,6 +1,9 @@
 use std::fmt::{Debug, Display};
 
-pub trait Arguments: Display + Debug {}
+pub trait Arguments: Display + Debug {
+    /// Formats each argument using its [`Debug`] implementation.
+    fn debug_arguments(&self) -> Vec<String>;
+}
 
 #[cfg(test)]
 pub(crate) use self::mock::*;
@@ -13,7 +16,11 @@ mod mock {
     #[derive(Debug)]
     pub(crate) struct ArgumentsMock;
 
-    impl Arguments for ArgumentsMock {}
+    impl Arguments for ArgumentsMock {
+        fn debug_arguments(&self) -> Vec<String> {
+            vec![String::from("mock")]
+        }
+    }
 
     impl std::fmt::Display for ArgumentsMock {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
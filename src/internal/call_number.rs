@@ -0,0 +1,9 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_CALL_NUMBER: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns a number that is unique for each call to a mocked method and increases with each call.
+/// This allows ordering calls recorded by different methods and mocks.
+pub(crate) fn next_call_number() -> usize {
+    NEXT_CALL_NUMBER.fetch_add(1, Ordering::SeqCst)
+}
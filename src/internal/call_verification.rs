@@ -0,0 +1,108 @@
+use crate::internal::expected_calls::ExpectedCalls;
+use crate::internal::matcher::ArgumentsMatcher;
+use crate::internal::method::Method;
+use nameof::name_of;
+use std::borrow::Cow;
+use std::fmt::{self, Debug};
+use std::thread::panicking;
+
+/// Verifies the calls that were made to a method after they were made.
+/// Verifications are created by the `verify_<method_name>` methods of a generated mock.
+///
+/// The number of calls matching the argument matchers is checked when
+/// [`CallVerification::times`] is called. A verification that is dropped
+/// without calling [`CallVerification::times`] expects exactly one matching call.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, Strictness};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait MessageSender {
+///     fn send_message(&self, recipient: &str, message: &str);
+/// }
+///
+/// let mut message_sender = MessageSenderMock::new();
+/// message_sender.set_strictness(Strictness::Nice);
+///
+/// message_sender.send_message("Paul", "Hello Paul");
+/// message_sender.send_message("Jane", "Hello Jane");
+/// message_sender.send_message("Paul", "Goodbye Paul");
+///
+/// message_sender
+///     .verify_send_message(|arg| arg.partial_eq("Paul"), |arg| arg.any())
+///     .times(2);
+/// message_sender.verify_send_message(|arg| arg.partial_eq("Jane"), |arg| arg.any());
+/// ```
+pub struct CallVerification<'mock, 'a, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    method: Cow<'a, Method<'mock, A, R>>,
+    matcher: Option<A>,
+}
+
+impl<'mock, 'a, A, R> CallVerification<'mock, 'a, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    pub(crate) fn new(method: &'a Method<'mock, A, R>, matcher: A) -> Self {
+        Self {
+            method: Cow::Borrowed(method),
+            matcher: Some(matcher),
+        }
+    }
+
+    /// Creates a verification of a copy of the given method.
+    /// Used for the instantiations of generic methods, which can't be borrowed.
+    pub(crate) fn new_owned(method: Method<'mock, A, R>, matcher: A) -> Self {
+        Self {
+            method: Cow::Owned(method),
+            matcher: Some(matcher),
+        }
+    }
+
+    /// Verifies that the number of recorded calls matching the argument matchers
+    /// is in the given range, panicking otherwise.
+    /// See [`MethodCallBuilder::times`] for the accepted ranges.
+    ///
+    /// [`MethodCallBuilder::times`]: crate::MethodCallBuilder::times
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
+    pub fn times<E>(mut self, expected_calls: E)
+    where
+        E: Into<ExpectedCalls>,
+    {
+        let matcher = self.matcher.take().unwrap();
+        self.method
+            .verify_recorded_calls_unwrap(&matcher, &expected_calls.into());
+    }
+}
+
+impl<'mock, 'a, A, R> Debug for CallVerification<'mock, 'a, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type CallVerification<'mock, 'a, A, R>))
+            .field(name_of!(method in Self), &self.method)
+            .field(name_of!(matcher in Self), &self.matcher)
+            .finish()
+    }
+}
+
+impl<'mock, 'a, A, R> Drop for CallVerification<'mock, 'a, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn drop(&mut self) {
+        if let Some(matcher) = self.matcher.take() {
+            if !panicking() {
+                self.method
+                    .verify_recorded_calls_unwrap(&matcher, &ExpectedCalls::default());
+            }
+        }
+    }
+}
@@ -1,24 +1,79 @@
-use crate::internal::matcher::ArgumentsMatcher;
-use crate::internal::return_value::ReturnValueGenerator;
-use std::rc::Rc;
+use nameof::name_of;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
 
-#[cfg(rustc_is_nightly)]
-use crate::internal::return_value::Cloned;
+/// Creates the return value for expected calls that don't specify one.
+pub type DefaultReturnValueFn<T> = fn() -> T;
 
-pub trait DefaultReturnValue<A>: Sized {
-    fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, Self>>> {
-        None
+/// Selects [`Default::default`] as [`DefaultReturnValueFn`] for types implementing [`Default`]
+/// without requiring specialization.
+///
+/// `(&&DefaultReturnValueSelector::<T>::new()).default_return_value()` resolves to
+/// [`SelectDefaultReturnValue`] if `T: Default` and falls back to
+/// [`SelectNoDefaultReturnValue`] otherwise.
+pub struct DefaultReturnValueSelector<T>(PhantomData<T>);
+
+impl<T> DefaultReturnValueSelector<T> {
+    pub fn new() -> Self {
+        DefaultReturnValueSelector(PhantomData)
     }
 }
 
-impl<A, T> DefaultReturnValue<A> for T where A: for<'args> ArgumentsMatcher<'args> {}
+pub trait SelectDefaultReturnValue<T> {
+    fn default_return_value(&self) -> Option<DefaultReturnValueFn<T>>;
+}
 
-#[cfg(rustc_is_nightly)]
-impl<A> DefaultReturnValue<A> for ()
+impl<T> SelectDefaultReturnValue<T> for &DefaultReturnValueSelector<T>
 where
-    A: for<'args> ArgumentsMatcher<'args>,
+    T: Default,
 {
-    fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, ()>>> {
-        Some(Rc::new(Cloned(())))
+    fn default_return_value(&self) -> Option<DefaultReturnValueFn<T>> {
+        Some(<T as Default>::default)
+    }
+}
+
+pub trait SelectNoDefaultReturnValue<T> {
+    fn default_return_value(&self) -> Option<DefaultReturnValueFn<T>>;
+}
+
+impl<T> SelectNoDefaultReturnValue<T> for DefaultReturnValueSelector<T> {
+    fn default_return_value(&self) -> Option<DefaultReturnValueFn<T>> {
+        None
+    }
+}
+
+impl<T> Default for DefaultReturnValueSelector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for DefaultReturnValueSelector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type DefaultReturnValueSelector<T>))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct NoDefault;
+
+    #[test]
+    fn selects_default_for_types_implementing_default() {
+        let default_return_value =
+            (&&DefaultReturnValueSelector::<String>::new()).default_return_value();
+
+        assert_eq!(Some(String::new()), default_return_value.map(|f| f()));
+    }
+
+    #[test]
+    fn selects_nothing_for_types_not_implementing_default() {
+        let default_return_value =
+            (&&DefaultReturnValueSelector::<NoDefault>::new()).default_return_value();
+
+        assert!(default_return_value.is_none());
     }
 }
@@ -0,0 +1,333 @@
+use crate::internal::expected_calls::ExpectedCalls;
+use crate::internal::fmt::DisplayTimes;
+use crate::internal::grammar::Grammar;
+use crate::internal::location::DisplayDefinedAt;
+use crate::internal::lock::{lock, wait_until};
+use std::fmt::{self, Display};
+use std::panic::Location;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+/// A handle to an expected call.
+/// Handles are created using [`MethodCallBuilder::handle`].
+///
+/// Other expected calls can depend on the expected call
+/// using [`MethodCallBuilder::after`].
+/// The handle can also be used to inspect how often the expected call was called
+/// after the [`MethodCallBuilder`] was dropped.
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Counter {
+///     fn increment(&self);
+/// }
+///
+/// let mut counter = CounterMock::new();
+/// let increment = counter.expect_increment().times(2..=3).handle();
+///
+/// counter.increment();
+/// assert_eq!(1, increment.call_count());
+/// assert!(!increment.is_satisfied());
+///
+/// counter.increment();
+/// counter.increment();
+/// assert!(increment.is_satisfied());
+/// assert!(increment.is_saturated());
+/// ```
+///
+/// [`MethodCallBuilder`]: crate::MethodCallBuilder
+/// [`MethodCallBuilder::handle`]: crate::MethodCallBuilder::handle
+/// [`MethodCallBuilder::after`]: crate::MethodCallBuilder::after
+#[derive(Clone, Debug)]
+pub struct ExpectationHandle {
+    description: Arc<str>,
+    location: Option<&'static Location<'static>>,
+    state: Arc<ExpectationState>,
+}
+
+impl ExpectationHandle {
+    pub(crate) fn new(
+        description: String,
+        location: Option<&'static Location<'static>>,
+        state: Arc<ExpectationState>,
+    ) -> Self {
+        Self {
+            description: Arc::from(description),
+            location,
+            state,
+        }
+    }
+
+    /// Returns how often the expected call was called.
+    pub fn call_count(&self) -> u64 {
+        self.state.actual_number_of_calls()
+    }
+
+    /// Returns `true` if the expected call was called the expected number of times.
+    pub fn is_satisfied(&self) -> bool {
+        self.state.was_called_expected_number_of_times()
+    }
+
+    /// Returns `true` if the expected call was called the maximum number of times
+    /// and does not accept any more calls.
+    pub fn is_saturated(&self) -> bool {
+        !self.state.accepts_more_calls()
+    }
+
+    /// Returns how often the expected call is expected to be called.
+    pub fn expected_calls(&self) -> ExpectedCalls {
+        self.state.expected_calls()
+    }
+
+    /// Blocks until the expected call was called the expected number of times,
+    /// e.g. by another thread.
+    ///
+    /// # Panics
+    /// Panics if the expected call is not satisfied before the timeout elapses.
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
+    pub fn wait_satisfied(&self, timeout: Duration) {
+        if !self.state.wait_until_satisfied(timeout) {
+            panic!(
+                "\n\nTimed out after {:?} waiting for the expected call to be satisfied:\n{}\n",
+                timeout, self
+            );
+        }
+    }
+
+    pub(crate) fn state(&self) -> &ExpectationState {
+        &self.state
+    }
+
+    pub(crate) fn description(&self) -> String {
+        self.description.to_string()
+    }
+
+    /// Returns `true` if both handles refer to the same expected call.
+    pub(crate) fn is_same(&self, other: &ExpectationHandle) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Display for ExpectationHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}{}",
+            self.description,
+            self.state,
+            DisplayDefinedAt(self.location)
+        )
+    }
+}
+
+/// The number of expected and actual calls of an expected call.
+/// Records the return of a call when dropped, which also happens when producing the return value panics.
+pub(crate) struct RecordReturnOnDrop<'a>(pub(crate) &'a ExpectationState);
+
+impl<'a> Drop for RecordReturnOnDrop<'a> {
+    fn drop(&mut self) {
+        self.0.record_return();
+    }
+}
+
+/// This state is shared with the [`Sequence`]s the expected call is part of.
+///
+/// [`Sequence`]: crate::Sequence
+#[derive(Debug, Default)]
+pub(crate) struct ExpectationState {
+    /// Must not stay locked while locking `returned_calls`,
+    /// because waiting for calls locks them in the opposite order.
+    /// [`None`] until the expected calls are set explicitly.
+    expected_calls: Mutex<Option<ExpectedCalls>>,
+    actual_number_of_calls: Mutex<u64>,
+    /// The number of calls whose return value was produced.
+    /// Waiting for calls uses this instead of `actual_number_of_calls`,
+    /// so that it only returns once the calls have returned.
+    returned_calls: Mutex<u64>,
+    /// Notified whenever a call returns.
+    call_returned: Condvar,
+    /// The grammars this expected call is part of, together with its symbol in each grammar.
+    grammars: Mutex<Vec<(Grammar, usize)>>,
+}
+
+impl Clone for ExpectationState {
+    fn clone(&self) -> Self {
+        Self {
+            expected_calls: Mutex::new(lock(&self.expected_calls).clone()),
+            actual_number_of_calls: Mutex::new(self.actual_number_of_calls()),
+            returned_calls: Mutex::new(*lock(&self.returned_calls)),
+            call_returned: Condvar::new(),
+            grammars: Mutex::new(lock(&self.grammars).clone()),
+        }
+    }
+}
+
+impl ExpectationState {
+    pub(crate) fn expected_calls(&self) -> ExpectedCalls {
+        lock(&self.expected_calls).clone().unwrap_or_default()
+    }
+
+    pub(crate) fn set_expected_calls(&self, expected_calls: ExpectedCalls) {
+        *lock(&self.expected_calls) = Some(expected_calls);
+    }
+
+    /// Returns `true` if the expected calls were set explicitly
+    /// instead of using the default of exactly one call.
+    pub(crate) fn has_explicit_expected_calls(&self) -> bool {
+        lock(&self.expected_calls).is_some()
+    }
+
+    pub(crate) fn actual_number_of_calls(&self) -> u64 {
+        *lock(&self.actual_number_of_calls)
+    }
+
+    pub(crate) fn record_call(&self) {
+        *lock(&self.actual_number_of_calls) += 1;
+    }
+
+    /// Records that the return value of a call recorded using [`ExpectationState::record_call`]
+    /// was produced and notifies the threads waiting for the expected call.
+    pub(crate) fn record_return(&self) {
+        *lock(&self.returned_calls) += 1;
+        self.call_returned.notify_all();
+    }
+
+    /// Blocks until the expected call was called the expected number of times
+    /// and the calls have returned, or the timeout elapses.
+    /// Returns `true` if the expected call is satisfied.
+    pub(crate) fn wait_until_satisfied(&self, timeout: Duration) -> bool {
+        let (_returned_calls, satisfied) = wait_until(
+            &self.returned_calls,
+            &self.call_returned,
+            timeout,
+            |&returned_calls| self.expected_calls().contains(returned_calls),
+        );
+
+        satisfied
+    }
+
+    pub(crate) fn add_grammar(&self, grammar: Grammar, symbol: usize) {
+        lock(&self.grammars).push((grammar, symbol));
+    }
+
+    pub(crate) fn is_in_grammar(&self) -> bool {
+        !lock(&self.grammars).is_empty()
+    }
+
+    /// Returns the grammars this expected call is part of, together with its symbol in each grammar.
+    pub(crate) fn grammars(&self) -> Vec<(Grammar, usize)> {
+        lock(&self.grammars).clone()
+    }
+
+    pub(crate) fn was_called_expected_number_of_times(&self) -> bool {
+        self.expected_calls()
+            .contains(self.actual_number_of_calls())
+    }
+
+    pub(crate) fn accepts_more_calls(&self) -> bool {
+        match self.expected_calls().max_value() {
+            Some(max_value) => self.actual_number_of_calls() < max_value,
+            None => true,
+        }
+    }
+}
+
+impl Display for ExpectationState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}, was called {}",
+            self.expected_calls(),
+            DisplayTimes(self.actual_number_of_calls())
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn accepts_more_calls_until_maximum_is_reached() {
+        let state = ExpectationState::default();
+        state.set_expected_calls(2.into());
+
+        assert!(state.accepts_more_calls());
+        state.record_call();
+        assert!(state.accepts_more_calls());
+        state.record_call();
+        assert!(!state.accepts_more_calls());
+    }
+
+    #[test]
+    fn handle_reports_state_of_expected_call() {
+        let state = Arc::new(ExpectationState::default());
+        state.set_expected_calls((1..=2).into());
+        let handle = ExpectationHandle::new(String::from("foo()"), None, state.clone());
+
+        assert_eq!(0, handle.call_count());
+        assert!(!handle.is_satisfied());
+        assert!(!handle.is_saturated());
+
+        state.record_call();
+        state.record_call();
+
+        assert_eq!(2, handle.call_count());
+        assert!(handle.is_satisfied());
+        assert!(handle.is_saturated());
+        assert_eq!(ExpectedCalls::from(1..=2), handle.expected_calls());
+    }
+
+    #[test]
+    fn clones_do_not_share_number_of_calls() {
+        let state = ExpectationState::default();
+        let clone = state.clone();
+
+        clone.record_call();
+
+        assert_eq!(0, state.actual_number_of_calls());
+        assert_eq!(1, clone.actual_number_of_calls());
+    }
+
+    #[test]
+    fn waits_until_expected_call_is_satisfied() {
+        let state = Arc::new(ExpectationState::default());
+        state.set_expected_calls(2.into());
+
+        let handle = std::thread::spawn({
+            let state = state.clone();
+            move || {
+                for _ in 0..2 {
+                    state.record_call();
+                    state.record_return();
+                }
+            }
+        });
+
+        assert!(state.wait_until_satisfied(Duration::from_secs(10)));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn waiting_times_out_if_calls_have_not_returned() {
+        let state = ExpectationState::default();
+        state.set_expected_calls(1.into());
+        state.record_call();
+
+        assert!(!state.wait_until_satisfied(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn waiting_times_out_if_expected_call_is_not_satisfied() {
+        let state = ExpectationState::default();
+        state.set_expected_calls(1.into());
+
+        assert!(!state.wait_until_satisfied(Duration::from_millis(10)));
+    }
+}
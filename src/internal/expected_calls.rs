@@ -32,6 +32,16 @@ impl ExpectedCalls {
         }
     }
 
+    pub(crate) fn min_value(&self) -> u64 {
+        match self.0 {
+            ExpectedCallsKind::Exact(expected) => expected,
+            ExpectedCallsKind::AtLeast(min) => min,
+            ExpectedCallsKind::Between { start, .. } => start,
+            ExpectedCallsKind::BetweenInclusive { start, .. } => start,
+            ExpectedCallsKind::AtMost(_) | ExpectedCallsKind::Any => 0,
+        }
+    }
+
     pub(crate) fn max_value(&self) -> Option<u64> {
         match self.0 {
             ExpectedCallsKind::Exact(expected) => Some(expected),
@@ -114,6 +124,20 @@ impl Display for ExpectedCalls {
 mod test {
     use super::*;
 
+    #[test]
+    fn min_value_is_zero_without_lower_bound() {
+        assert_eq!(0, ExpectedCalls::from(..).min_value());
+        assert_eq!(0, ExpectedCalls::from(..=3).min_value());
+    }
+
+    #[test]
+    fn min_value_is_lower_bound() {
+        assert_eq!(3, ExpectedCalls::from(3).min_value());
+        assert_eq!(3, ExpectedCalls::from(3..).min_value());
+        assert_eq!(3, ExpectedCalls::from(3..5).min_value());
+        assert_eq!(3, ExpectedCalls::from(3..=5).min_value());
+    }
+
     #[test]
     fn exact_matches_specified_value() {
         assert!(ExpectedCalls(ExpectedCallsKind::Exact(4)).contains(4));
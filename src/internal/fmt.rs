@@ -1,5 +1,7 @@
 mod debug;
+mod diff;
 mod display;
 
 pub use self::debug::*;
+pub(crate) use self::diff::*;
 pub(crate) use self::display::*;
@@ -1,4 +1,6 @@
+use nameof::name_of;
 use std::fmt::{self, Debug};
+use std::marker::PhantomData;
 
 /// A wrapper around a [`MaybeDebug`] type that implements [`Debug`].
 pub struct MaybeDebugWrapper<'a>(pub &'a dyn MaybeDebug);
@@ -25,6 +27,74 @@ where
     T: fmt::Debug + ?Sized,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A function that formats a value using [`Debug`] or a fallback.
+pub type DebugFn<T> = fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result;
+
+/// Selects a [`DebugFn`] for a type without requiring specialization.
+///
+/// The generated code uses autoref-based specialization to pick the
+/// [`Debug`] implementation when it is available:
+/// `(&&DebugFnSelector::<T>::new()).debug_fn()` resolves to [`SelectDebugFn`]
+/// if `T: Debug` and falls back to [`SelectFallbackDebugFn`] otherwise.
+/// This only works when the type is known at the call site, which is
+/// why the selection happens in the generated code.
+pub struct DebugFnSelector<T>(PhantomData<T>);
+
+impl<T> DebugFnSelector<T> {
+    pub fn new() -> Self {
+        DebugFnSelector(PhantomData)
+    }
+
+    /// Creates a selector for the type of the given value.
+    pub fn of(_value: &T) -> Self {
+        Self::new()
+    }
+}
+
+pub trait SelectDebugFn<T> {
+    fn debug_fn(&self) -> DebugFn<T>;
+}
+
+impl<T> SelectDebugFn<T> for &DebugFnSelector<T>
+where
+    T: Debug,
+{
+    fn debug_fn(&self) -> DebugFn<T> {
+        <T as Debug>::fmt
+    }
+}
+
+pub trait SelectFallbackDebugFn<T> {
+    fn debug_fn(&self) -> DebugFn<T>;
+}
+
+impl<T> SelectFallbackDebugFn<T> for DebugFnSelector<T> {
+    fn debug_fn(&self) -> DebugFn<T> {
+        <T as MaybeDebug>::fmt
+    }
+}
+
+impl<T> Default for DebugFnSelector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for DebugFnSelector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type DebugFnSelector<T>)).finish()
+    }
+}
+
+/// Formats a value using the given [`DebugFn`].
+pub struct DebugWith<'a, T>(pub &'a T, pub DebugFn<T>);
+
+impl<'a, T> Debug for DebugWith<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
     }
 }
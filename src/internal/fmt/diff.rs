@@ -0,0 +1,174 @@
+use std::env;
+use std::fmt::{self, Display};
+
+const COLOR_ENV_VARIABLE: &str = "MOCKIATO_COLOR";
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// Displays a line-based diff between two pretty-printed values.
+/// Lines only present in the expected value are prefixed with `-`,
+/// lines only present in the actual value are prefixed with `+`.
+pub(crate) struct DisplayDiff<'a> {
+    pub(crate) expected: &'a str,
+    pub(crate) actual: &'a str,
+    pub(crate) colored: bool,
+}
+
+impl<'a> Display for DisplayDiff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in diff_lines(self.expected, self.actual) {
+            match line {
+                DiffLine::Unchanged(line) => writeln!(f, "  {}", line)?,
+                DiffLine::Expected(line) => {
+                    writeln!(f, "{}", ColoredLine(RED, '-', line, self.colored))?
+                }
+                DiffLine::Actual(line) => {
+                    writeln!(f, "{}", ColoredLine(GREEN, '+', line, self.colored))?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct ColoredLine<'a>(&'static str, char, &'a str, bool);
+
+impl<'a> Display for ColoredLine<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ColoredLine(color, prefix, line, colored) = *self;
+
+        if colored {
+            write!(f, "{}{} {}{}", color, prefix, line, RESET)
+        } else {
+            write!(f, "{} {}", prefix, line)
+        }
+    }
+}
+
+/// Decides whether diffs should be colored.
+///
+/// The `MOCKIATO_COLOR` environment variable accepts `always`, `never` and `auto`.
+/// When set to `auto` or not set at all, diffs are colored if stderr is a TTY.
+pub(crate) fn use_colors() -> bool {
+    match env::var(COLOR_ENV_VARIABLE) {
+        Ok(ref value) if value == "always" => true,
+        Ok(ref value) if value == "never" => false,
+        _ => atty::is(atty::Stream::Stderr),
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum DiffLine<'a> {
+    Unchanged(&'a str),
+    Expected(&'a str),
+    Actual(&'a str),
+}
+
+/// Computes a line-based diff using the longest common subsequence of both inputs.
+fn diff_lines<'a>(expected: &'a str, actual: &'a str) -> Vec<DiffLine<'a>> {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+
+    // lengths[i][j] is the length of the longest common subsequence
+    // of expected[i..] and actual[j..]
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < expected.len() && j < actual.len() {
+        if expected[i] == actual[j] {
+            lines.push(DiffLine::Unchanged(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            lines.push(DiffLine::Expected(expected[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Actual(actual[j]));
+            j += 1;
+        }
+    }
+
+    lines.extend(expected[i..].iter().cloned().map(DiffLine::Expected));
+    lines.extend(actual[j..].iter().cloned().map(DiffLine::Actual));
+
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn identical_values_have_no_changed_lines() {
+        assert_eq!(
+            vec![DiffLine::Unchanged("foo"), DiffLine::Unchanged("bar")],
+            diff_lines("foo\nbar", "foo\nbar")
+        );
+    }
+
+    #[test]
+    fn changed_line_is_marked_as_removed_and_added() {
+        assert_eq!(
+            vec![
+                DiffLine::Unchanged("Person {"),
+                DiffLine::Expected("    age: 30,"),
+                DiffLine::Actual("    age: 31,"),
+                DiffLine::Unchanged("}"),
+            ],
+            diff_lines("Person {\n    age: 30,\n}", "Person {\n    age: 31,\n}")
+        );
+    }
+
+    #[test]
+    fn trailing_lines_are_included() {
+        assert_eq!(
+            vec![
+                DiffLine::Unchanged("foo"),
+                DiffLine::Expected("bar"),
+                DiffLine::Actual("baz"),
+                DiffLine::Actual("qux"),
+            ],
+            diff_lines("foo\nbar", "foo\nbaz\nqux")
+        );
+    }
+
+    #[test]
+    fn diff_is_displayed_without_colors() {
+        let diff = DisplayDiff {
+            expected: "foo\nbar",
+            actual: "foo\nbaz",
+            colored: false,
+        };
+
+        assert_eq!("  foo\n- bar\n+ baz\n", diff.to_string());
+    }
+
+    #[test]
+    fn diff_is_displayed_with_colors() {
+        let diff = DisplayDiff {
+            expected: "bar",
+            actual: "baz",
+            colored: true,
+        };
+
+        assert_eq!(
+            "\x1b[31m- bar\x1b[0m\n\x1b[32m+ baz\x1b[0m\n",
+            diff.to_string()
+        );
+    }
+}
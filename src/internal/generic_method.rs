@@ -0,0 +1,364 @@
+use crate::internal::ambiguity_resolution::AmbiguityResolution;
+use crate::internal::call_verification::CallVerification;
+use crate::internal::lock::lock;
+use crate::internal::matcher::ArgumentsMatcher;
+use crate::internal::method::Method;
+use crate::internal::recorded_call::RecordedCall;
+use crate::internal::strictness::Strictness;
+use crate::internal::verification_report::MockVerificationReport;
+use nameof::name_of;
+use std::any::{Any, TypeId};
+use std::fmt::{self, Debug};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A [`Method`] whose argument matcher and return types are erased.
+/// Used to store the instantiations of a generic method.
+pub trait ErasedMethod: Debug {
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn clone_erased(&self) -> Box<dyn ErasedMethod>;
+
+    fn expect_method_calls_in_order(&mut self);
+
+    fn set_ambiguity_resolution(&mut self, ambiguity_resolution: AmbiguityResolution);
+
+    fn set_strictness(&mut self, strictness: Strictness);
+
+    fn unexpected_calls(&self) -> Vec<(usize, String)>;
+
+    fn recorded_calls(&self) -> Vec<RecordedCall>;
+
+    fn verify_unwrap(&self);
+
+    fn verify_into(&self, report: &mut MockVerificationReport);
+
+    fn clear_expected_calls(&mut self);
+}
+
+/// An [`ErasedMethod`] that can be shared between threads.
+/// Used to store the instantiations of a generic method of a sync mock.
+pub trait SyncErasedMethod: ErasedMethod + Send + Sync {
+    fn clone_sync_erased(&self) -> Box<dyn SyncErasedMethod>;
+}
+
+/// Implemented by the trait objects that are stored in a [`GenericMethod`].
+pub trait ErasedMethodObject: ErasedMethod {
+    fn clone_object(&self) -> Box<Self>;
+}
+
+impl<A, R> ErasedMethod for Method<'static, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args> + 'static,
+    R: 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_erased(&self) -> Box<dyn ErasedMethod> {
+        Box::new(self.clone())
+    }
+
+    fn expect_method_calls_in_order(&mut self) {
+        Method::expect_method_calls_in_order(self)
+    }
+
+    fn set_ambiguity_resolution(&mut self, ambiguity_resolution: AmbiguityResolution) {
+        Method::set_ambiguity_resolution(self, ambiguity_resolution)
+    }
+
+    fn set_strictness(&mut self, strictness: Strictness) {
+        Method::set_strictness(self, strictness)
+    }
+
+    fn unexpected_calls(&self) -> Vec<(usize, String)> {
+        Method::unexpected_calls(self)
+    }
+
+    fn recorded_calls(&self) -> Vec<RecordedCall> {
+        Method::recorded_calls(self)
+    }
+
+    fn verify_unwrap(&self) {
+        Method::verify_unwrap(self)
+    }
+
+    fn verify_into(&self, report: &mut MockVerificationReport) {
+        Method::verify_into(self, report)
+    }
+
+    fn clear_expected_calls(&mut self) {
+        Method::clear_expected_calls(self)
+    }
+}
+
+impl<T> SyncErasedMethod for T
+where
+    T: ErasedMethod + Clone + Send + Sync + 'static,
+{
+    fn clone_sync_erased(&self) -> Box<dyn SyncErasedMethod> {
+        Box::new(self.clone())
+    }
+}
+
+impl ErasedMethodObject for dyn ErasedMethod {
+    fn clone_object(&self) -> Box<Self> {
+        self.clone_erased()
+    }
+}
+
+impl ErasedMethodObject for dyn SyncErasedMethod {
+    fn clone_object(&self) -> Box<Self> {
+        self.clone_sync_erased()
+    }
+}
+
+/// A method with type parameters.
+///
+/// Every combination of type arguments the method is used with is
+/// stored as a separate [`Method`], which is created on first use.
+/// The type arguments must be `'static` so that they can be identified by their [`TypeId`].
+pub struct GenericMethod<M>
+where
+    M: ErasedMethodObject + ?Sized,
+{
+    name: &'static str,
+    expect_method_calls_in_order: bool,
+    ambiguity_resolution: Option<AmbiguityResolution>,
+    strictness: Option<Strictness>,
+    instantiations: Mutex<Vec<(TypeId, Arc<M>)>>,
+}
+
+impl<M> GenericMethod<M>
+where
+    M: ErasedMethodObject + ?Sized,
+{
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            expect_method_calls_in_order: false,
+            ambiguity_resolution: None,
+            strictness: None,
+            instantiations: Mutex::default(),
+        }
+    }
+
+    /// Returns the instantiation for the type arguments `K`,
+    /// creating it using the given function if it does not exist yet.
+    pub fn instantiation_mut<K, A, R, F>(&mut self, create: F) -> &mut Method<'static, A, R>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        F: FnOnce() -> Box<M>,
+    {
+        // Only the reference to the instantiation that is stored in this method remains.
+        drop(self.get_or_create::<K, F>(create));
+
+        let instantiations = self
+            .instantiations
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (_, instantiation) = instantiations
+            .iter_mut()
+            .find(|(type_id, _)| *type_id == TypeId::of::<K>())
+            .unwrap();
+
+        Arc::get_mut(instantiation)
+            .expect("Generic methods must not be configured while they are being called")
+            .as_any_mut()
+            .downcast_mut()
+            .expect("The instantiation of a generic method has an unexpected type")
+    }
+
+    /// Calls the given function with the instantiation for the type arguments `K`,
+    /// creating it using the given function if it does not exist yet.
+    pub fn with_instantiation<K, A, R, F, C, T>(&self, create: F, call: C) -> T
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        F: FnOnce() -> Box<M>,
+        C: FnOnce(&Method<'static, A, R>) -> T,
+    {
+        let instantiation = self.get_or_create::<K, F>(create);
+
+        call(downcast(&*instantiation))
+    }
+
+    /// Creates a verification of the recorded calls to the instantiation for the type
+    /// arguments `K` matching the given matcher.
+    pub fn verify_calls<K, A, R, F>(
+        &self,
+        create: F,
+        matcher: A,
+    ) -> CallVerification<'static, '_, A, R>
+    where
+        K: 'static,
+        A: for<'args> ArgumentsMatcher<'args> + 'static,
+        R: 'static,
+        F: FnOnce() -> Box<M>,
+    {
+        let instantiation = self.get_or_create::<K, F>(create);
+
+        CallVerification::new_owned(downcast(&*instantiation).clone(), matcher)
+    }
+
+    pub fn expect_method_calls_in_order(&mut self) {
+        self.expect_method_calls_in_order = true;
+        self.for_each_instantiation_mut(ErasedMethod::expect_method_calls_in_order);
+    }
+
+    pub fn set_ambiguity_resolution(&mut self, ambiguity_resolution: AmbiguityResolution) {
+        self.ambiguity_resolution = Some(ambiguity_resolution);
+        self.for_each_instantiation_mut(|instantiation| {
+            instantiation.set_ambiguity_resolution(ambiguity_resolution)
+        });
+    }
+
+    pub fn set_strictness(&mut self, strictness: Strictness) {
+        self.strictness = Some(strictness);
+        self.for_each_instantiation_mut(|instantiation| instantiation.set_strictness(strictness));
+    }
+
+    pub fn unexpected_calls(&self) -> Vec<(usize, String)> {
+        let mut unexpected_calls: Vec<_> = self
+            .instantiations()
+            .iter()
+            .flat_map(|instantiation| instantiation.unexpected_calls())
+            .collect();
+        unexpected_calls.sort_by_key(|(call_number, _)| *call_number);
+        unexpected_calls
+    }
+
+    pub fn recorded_calls(&self) -> Vec<RecordedCall> {
+        let mut recorded_calls: Vec<_> = self
+            .instantiations()
+            .iter()
+            .flat_map(|instantiation| instantiation.recorded_calls())
+            .collect();
+        recorded_calls.sort_by_key(RecordedCall::sequence_number);
+        recorded_calls
+    }
+
+    pub fn verify_unwrap(&self) {
+        for instantiation in self.instantiations() {
+            instantiation.verify_unwrap();
+        }
+    }
+
+    pub fn verify_into(&self, report: &mut MockVerificationReport) {
+        for instantiation in self.instantiations() {
+            instantiation.verify_into(report);
+        }
+    }
+
+    pub fn clear_expected_calls(&mut self) {
+        self.for_each_instantiation_mut(ErasedMethod::clear_expected_calls);
+    }
+
+    fn get_or_create<K, F>(&self, create: F) -> Arc<M>
+    where
+        K: 'static,
+        F: FnOnce() -> Box<M>,
+    {
+        let type_id = TypeId::of::<K>();
+        let mut instantiations = lock(&self.instantiations);
+
+        if let Some((_, instantiation)) = instantiations.iter().find(|(id, _)| *id == type_id) {
+            return instantiation.clone();
+        }
+
+        let mut instantiation = create();
+
+        if self.expect_method_calls_in_order {
+            instantiation.expect_method_calls_in_order();
+        }
+
+        if let Some(ambiguity_resolution) = self.ambiguity_resolution {
+            instantiation.set_ambiguity_resolution(ambiguity_resolution);
+        }
+
+        if let Some(strictness) = self.strictness {
+            instantiation.set_strictness(strictness);
+        }
+
+        let instantiation = Arc::from(instantiation);
+        instantiations.push((type_id, Arc::clone(&instantiation)));
+        instantiation
+    }
+
+    /// Returns the existing instantiations. The lock is released before they are used,
+    /// so that instantiations can be verified while other threads are calling the method.
+    fn instantiations(&self) -> Vec<Arc<M>> {
+        lock(&self.instantiations)
+            .iter()
+            .map(|(_, instantiation)| instantiation.clone())
+            .collect()
+    }
+
+    fn for_each_instantiation_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut M),
+    {
+        let instantiations = self
+            .instantiations
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        for (_, instantiation) in instantiations.iter_mut() {
+            f(Arc::get_mut(instantiation)
+                .expect("Generic methods must not be configured while they are being called"));
+        }
+    }
+}
+
+fn downcast<M, A, R>(instantiation: &M) -> &Method<'static, A, R>
+where
+    M: ErasedMethodObject + ?Sized,
+    A: for<'args> ArgumentsMatcher<'args> + 'static,
+    R: 'static,
+{
+    instantiation
+        .as_any()
+        .downcast_ref()
+        .expect("The instantiation of a generic method has an unexpected type")
+}
+
+impl<M> Clone for GenericMethod<M>
+where
+    M: ErasedMethodObject + ?Sized,
+{
+    fn clone(&self) -> Self {
+        let instantiations = lock(&self.instantiations)
+            .iter()
+            .map(|(type_id, instantiation)| (*type_id, Arc::from(instantiation.clone_object())))
+            .collect();
+
+        Self {
+            name: self.name,
+            expect_method_calls_in_order: self.expect_method_calls_in_order,
+            ambiguity_resolution: self.ambiguity_resolution,
+            strictness: self.strictness,
+            instantiations: Mutex::new(instantiations),
+        }
+    }
+}
+
+impl<M> Debug for GenericMethod<M>
+where
+    M: ErasedMethodObject + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type GenericMethod<M>))
+            .field(name_of!(name in Self), &self.name)
+            .field(name_of!(instantiations in Self), &self.instantiations())
+            .finish()
+    }
+}
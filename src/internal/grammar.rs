@@ -0,0 +1,605 @@
+use crate::internal::expectation::ExpectationHandle;
+use crate::internal::expected_calls::ExpectedCalls;
+use crate::internal::lock::lock;
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Describes the calls that are allowed by a [`Grammar`].
+///
+/// Rules are built from expected calls using [`Rule::call`]
+/// and combined using [`Rule::then`], [`Rule::or`], [`Rule::repeat`] and [`Rule::optional`].
+#[derive(Clone, Debug)]
+pub struct Rule(RuleKind);
+
+#[derive(Clone, Debug)]
+enum RuleKind {
+    Call(ExpectationHandle),
+    Sequence(Vec<Rule>),
+    Alternation(Vec<Rule>),
+    Repetition(Box<Rule>, ExpectedCalls),
+}
+
+impl Rule {
+    /// A single call to the given expected call.
+    pub fn call(expectation: &ExpectationHandle) -> Self {
+        Rule(RuleKind::Call(expectation.clone()))
+    }
+
+    /// This rule followed by the given rule.
+    pub fn then(self, rule: Rule) -> Self {
+        match self.0 {
+            RuleKind::Sequence(mut rules) => {
+                rules.push(rule);
+                Rule(RuleKind::Sequence(rules))
+            }
+            kind => Rule(RuleKind::Sequence(vec![Rule(kind), rule])),
+        }
+    }
+
+    /// Either this rule or the given rule.
+    pub fn or(self, rule: Rule) -> Self {
+        match self.0 {
+            RuleKind::Alternation(mut rules) => {
+                rules.push(rule);
+                Rule(RuleKind::Alternation(rules))
+            }
+            kind => Rule(RuleKind::Alternation(vec![Rule(kind), rule])),
+        }
+    }
+
+    /// This rule repeated the given number of times.
+    /// See [`MethodCallBuilder::times`] for the accepted values.
+    ///
+    /// [`MethodCallBuilder::times`]: crate::MethodCallBuilder::times
+    pub fn repeat<E>(self, times: E) -> Self
+    where
+        E: Into<ExpectedCalls>,
+    {
+        Rule(RuleKind::Repetition(Box::new(self), times.into()))
+    }
+
+    /// This rule or nothing at all.
+    pub fn optional(self) -> Self {
+        self.repeat(0..=1)
+    }
+
+    fn expectations(&self) -> Vec<&ExpectationHandle> {
+        match self.0 {
+            RuleKind::Call(ref expectation) => vec![expectation],
+            RuleKind::Sequence(ref rules) | RuleKind::Alternation(ref rules) => {
+                rules.iter().flat_map(Rule::expectations).collect()
+            }
+            RuleKind::Repetition(ref rule, _) => rule.expectations(),
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            RuleKind::Call(ref expectation) => write!(f, "{}", expectation.description()),
+            RuleKind::Sequence(ref rules) => write!(f, "{}", DisplayRules(rules, " ")),
+            RuleKind::Alternation(ref rules) => write!(f, "({})", DisplayRules(rules, " | ")),
+            RuleKind::Repetition(ref rule, ref times) => {
+                let rule = DisplayNested(rule);
+                match (times.min_value(), times.max_value()) {
+                    (0, None) => write!(f, "{}*", rule),
+                    (1, None) => write!(f, "{}+", rule),
+                    (0, Some(1)) => write!(f, "{}?", rule),
+                    (min, None) => write!(f, "{}{{{},}}", rule, min),
+                    (min, Some(max)) if min == max => write!(f, "{}{{{}}}", rule, min),
+                    (min, Some(max)) => write!(f, "{}{{{},{}}}", rule, min, max),
+                }
+            }
+        }
+    }
+}
+
+struct DisplayRules<'a>(&'a [Rule], &'static str);
+
+impl<'a> Display for DisplayRules<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, rule) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, "{}", self.1)?;
+            }
+            write!(f, "{}", DisplayNested(rule))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Wraps sequences in parentheses
+struct DisplayNested<'a>(&'a Rule);
+
+impl<'a> Display for DisplayNested<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.0).0 {
+            RuleKind::Sequence(ref rules) if rules.len() > 1 => write!(f, "({})", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// Restricts the order of calls to expected calls using a [`Rule`].
+/// This can express protocols such as `connect (send | ping)* disconnect?`.
+///
+/// The expected calls that are part of a grammar can be called any amount of times,
+/// as long as the calls match the grammar.
+/// Their number of calls is defined by the grammar alone,
+/// which is why they must not use [`MethodCallBuilder::times`].
+/// A call that does not match the grammar panics and reports where the calls diverged.
+///
+/// The grammar is verified together with the mocks of its expected calls,
+/// so a mock reports a failure if the calls did not complete the grammar.
+/// This includes the `try_verify` and `checkpoint` methods as well as dropping the mock,
+/// unless the mock was disarmed.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, Grammar, Rule};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Connection {
+///     fn connect(&self);
+///     fn send(&self, message: &str);
+///     fn ping(&self);
+///     fn disconnect(&self);
+/// }
+///
+/// let mut connection = ConnectionMock::new();
+/// let connect = connection.expect_connect().handle();
+/// let send = connection.expect_send(|arg| arg.any()).handle();
+/// let ping = connection.expect_ping().handle();
+/// let disconnect = connection.expect_disconnect().handle();
+///
+/// let _grammar = Grammar::new(
+///     Rule::call(&connect)
+///         .then(Rule::call(&send).or(Rule::call(&ping)).repeat(..))
+///         .then(Rule::call(&disconnect).optional()),
+/// );
+///
+/// connection.connect();
+/// connection.ping();
+/// connection.send("foo");
+/// connection.ping();
+/// ```
+///
+/// [`MethodCallBuilder::times`]: crate::MethodCallBuilder::times
+#[derive(Clone, Debug)]
+pub struct Grammar(Arc<Mutex<GrammarState>>);
+
+impl Grammar {
+    /// Creates a grammar from the given rule.
+    /// The expected calls used in the rule can be called any amount of times from now on.
+    ///
+    /// # Panics
+    /// Panics if the number of calls of an expected call used in the rule
+    /// was already set using [`MethodCallBuilder::times`].
+    ///
+    /// [`MethodCallBuilder::times`]: crate::MethodCallBuilder::times
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
+    pub fn new(rule: Rule) -> Self {
+        let mut symbols: Vec<ExpectationHandle> = Vec::new();
+
+        for expectation in rule.expectations() {
+            if !symbols.iter().any(|symbol| symbol.is_same(expectation)) {
+                symbols.push(expectation.clone());
+            }
+        }
+
+        let conflicting_expectation = symbols.iter().find(|symbol| {
+            symbol.state().has_explicit_expected_calls() && !symbol.state().is_in_grammar()
+        });
+
+        if let Some(expectation) = conflicting_expectation {
+            panic!(
+                "\n\nThe expected call {} cannot be part of a grammar, because its number of \
+                 calls was set using `times(..)`.\nThe number of calls of expected calls in a \
+                 grammar is defined by the grammar.\n",
+                expectation
+            );
+        }
+
+        let automaton = Automaton::new(&rule, &symbols);
+        let current_states = automaton.closure(Some(automaton.start).into_iter().collect());
+
+        let grammar = Grammar(Arc::new(Mutex::new(GrammarState {
+            description: rule.to_string(),
+            symbols: symbols.iter().map(ExpectationHandle::description).collect(),
+            automaton,
+            current_states,
+            calls: Vec::new(),
+        })));
+
+        for (symbol, expectation) in symbols.iter().enumerate() {
+            expectation.state().set_expected_calls((..).into());
+            expectation.state().add_grammar(grammar.clone(), symbol);
+        }
+
+        grammar
+    }
+
+    /// Locks the given grammars, so that a call can be checked against all of them
+    /// and advance them without other calls interleaving.
+    /// The grammars are locked in a consistent order to prevent deadlocks.
+    pub(crate) fn lock_all(grammars: &[(Grammar, usize)]) -> LockedGrammars<'_> {
+        let mut grammars: Vec<_> = grammars.iter().collect();
+        grammars.sort_by_key(|(grammar, _)| grammar.address());
+
+        LockedGrammars(
+            grammars
+                .into_iter()
+                .map(|(grammar, symbol)| (lock(&grammar.0), *symbol))
+                .collect(),
+        )
+    }
+
+    /// Returns an error describing the grammar if the calls did not complete it.
+    pub(crate) fn verify(&self) -> Result<(), GrammarError> {
+        let grammar = lock(&self.0);
+
+        if grammar.is_complete() {
+            Ok(())
+        } else {
+            Err(GrammarError::new(&grammar))
+        }
+    }
+
+    pub(crate) fn is_same(&self, other: &Grammar) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    fn address(&self) -> usize {
+        &*self.0 as *const Mutex<GrammarState> as usize
+    }
+}
+
+/// The grammars of an expected call, locked by [`Grammar::lock_all`].
+pub(crate) struct LockedGrammars<'a>(Vec<(MutexGuard<'a, GrammarState>, usize)>);
+
+impl<'a> LockedGrammars<'a> {
+    /// Checks whether all grammars accept a call to their symbol.
+    pub(crate) fn check(&self) -> Result<(), GrammarError> {
+        for (grammar, symbol) in &self.0 {
+            let next_states = grammar
+                .automaton
+                .next_states(&grammar.current_states, *symbol);
+
+            if next_states.is_empty() {
+                return Err(GrammarError::new(grammar));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Advances all grammars by a call to their symbol.
+    /// Must only be used after [`LockedGrammars::check`] succeeded.
+    pub(crate) fn advance(&mut self) {
+        for (grammar, symbol) in &mut self.0 {
+            let next_states = grammar
+                .automaton
+                .next_states(&grammar.current_states, *symbol);
+
+            grammar.current_states = next_states;
+            grammar.calls.push(*symbol);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct GrammarState {
+    description: String,
+    symbols: Vec<String>,
+    automaton: Automaton,
+    current_states: BTreeSet<usize>,
+    calls: Vec<usize>,
+}
+
+impl GrammarState {
+    fn is_complete(&self) -> bool {
+        self.current_states.contains(&self.automaton.accept)
+    }
+}
+
+/// The error returned when a call diverges from a grammar
+/// or when the calls did not complete a grammar.
+#[derive(Debug)]
+pub(crate) struct GrammarError {
+    grammar: String,
+    calls: Vec<String>,
+    expected_calls: Vec<String>,
+    may_end: bool,
+}
+
+impl GrammarError {
+    fn new(grammar: &GrammarState) -> Self {
+        let symbol_description = |symbol: &usize| grammar.symbols[*symbol].clone();
+
+        Self {
+            grammar: grammar.description.clone(),
+            calls: grammar.calls.iter().map(symbol_description).collect(),
+            expected_calls: grammar
+                .automaton
+                .possible_symbols(&grammar.current_states)
+                .iter()
+                .map(symbol_description)
+                .collect(),
+            may_end: grammar.is_complete(),
+        }
+    }
+}
+
+impl Display for GrammarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Grammar: {}", self.grammar)?;
+        writeln!(
+            f,
+            "The calls diverged from the grammar at position {}.",
+            self.calls.len() + 1
+        )?;
+
+        if !self.calls.is_empty() {
+            writeln!(f, "Calls matching the grammar:")?;
+            for (index, call) in self.calls.iter().enumerate() {
+                writeln!(f, "{}. {}", index + 1, call)?;
+            }
+        }
+
+        writeln!(f, "Expected one of:")?;
+        for expected_call in &self.expected_calls {
+            writeln!(f, "{}", expected_call)?;
+        }
+
+        if self.may_end {
+            writeln!(f, "no further calls")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A nondeterministic finite automaton that accepts the calls described by a [`Rule`].
+#[derive(Debug)]
+struct Automaton {
+    /// The transitions of each state. Transitions without a symbol are epsilon transitions.
+    transitions: Vec<Vec<(Option<usize>, usize)>>,
+    start: usize,
+    accept: usize,
+}
+
+impl Automaton {
+    fn new(rule: &Rule, symbols: &[ExpectationHandle]) -> Self {
+        let mut automaton = Automaton {
+            transitions: vec![Vec::new()],
+            start: 0,
+            accept: 0,
+        };
+
+        automaton.accept = automaton.add_rule(rule, symbols, automaton.start);
+        automaton
+    }
+
+    fn add_state(&mut self) -> usize {
+        self.transitions.push(Vec::new());
+        self.transitions.len() - 1
+    }
+
+    fn add_transition(&mut self, from: usize, symbol: Option<usize>, to: usize) {
+        self.transitions[from].push((symbol, to));
+    }
+
+    /// Adds the states for a rule starting at the given state
+    /// and returns the state that is reached after the rule was matched.
+    fn add_rule(&mut self, rule: &Rule, symbols: &[ExpectationHandle], start: usize) -> usize {
+        match rule.0 {
+            RuleKind::Call(ref expectation) => {
+                let symbol = symbols
+                    .iter()
+                    .position(|symbol| symbol.is_same(expectation))
+                    .expect("Symbol for expectation not found");
+                let end = self.add_state();
+                self.add_transition(start, Some(symbol), end);
+                end
+            }
+            RuleKind::Sequence(ref rules) => rules
+                .iter()
+                .fold(start, |state, rule| self.add_rule(rule, symbols, state)),
+            RuleKind::Alternation(ref rules) => {
+                let end = self.add_state();
+                for rule in rules {
+                    let state = self.add_rule(rule, symbols, start);
+                    self.add_transition(state, None, end);
+                }
+                end
+            }
+            RuleKind::Repetition(ref rule, ref times) => {
+                self.add_repetition(rule, times, symbols, start)
+            }
+        }
+    }
+
+    fn add_repetition(
+        &mut self,
+        rule: &Rule,
+        times: &ExpectedCalls,
+        symbols: &[ExpectationHandle],
+        start: usize,
+    ) -> usize {
+        let min = times.min_value();
+        let mut state = start;
+
+        match times.max_value() {
+            Some(max) if max < min => {
+                // Nothing can match an empty range
+                self.add_state()
+            }
+            Some(max) => {
+                for _ in 0..min {
+                    state = self.add_rule(rule, symbols, state);
+                }
+
+                for _ in min..max {
+                    let end = self.add_state();
+                    let repetition_end = self.add_rule(rule, symbols, state);
+                    self.add_transition(state, None, end);
+                    self.add_transition(repetition_end, None, end);
+                    state = end;
+                }
+
+                state
+            }
+            None => {
+                for _ in 0..min {
+                    state = self.add_rule(rule, symbols, state);
+                }
+
+                let loop_state = self.add_state();
+                self.add_transition(state, None, loop_state);
+                let repetition_end = self.add_rule(rule, symbols, loop_state);
+                self.add_transition(repetition_end, None, loop_state);
+                loop_state
+            }
+        }
+    }
+
+    /// Returns the given states and all states reachable from them using epsilon transitions.
+    fn closure(&self, states: BTreeSet<usize>) -> BTreeSet<usize> {
+        let mut closure = states.clone();
+        let mut pending: Vec<_> = states.into_iter().collect();
+
+        while let Some(state) = pending.pop() {
+            for &(symbol, target) in &self.transitions[state] {
+                if symbol.is_none() && closure.insert(target) {
+                    pending.push(target);
+                }
+            }
+        }
+
+        closure
+    }
+
+    fn next_states(&self, states: &BTreeSet<usize>, symbol: usize) -> BTreeSet<usize> {
+        let next_states = states
+            .iter()
+            .flat_map(|&state| self.transitions[state].iter())
+            .filter(|&&(transition_symbol, _)| transition_symbol == Some(symbol))
+            .map(|&(_, target)| target)
+            .collect();
+
+        self.closure(next_states)
+    }
+
+    fn possible_symbols(&self, states: &BTreeSet<usize>) -> BTreeSet<usize> {
+        states
+            .iter()
+            .flat_map(|&state| self.transitions[state].iter())
+            .filter_map(|&(symbol, _)| symbol)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::internal::expectation::ExpectationState;
+
+    fn handle(description: &str) -> ExpectationHandle {
+        ExpectationHandle::new(
+            description.to_string(),
+            None,
+            Arc::new(ExpectationState::default()),
+        )
+    }
+
+    fn accepts(rule: &Rule, symbols: &[ExpectationHandle], calls: &[usize]) -> bool {
+        let automaton = Automaton::new(rule, symbols);
+        let mut states = automaton.closure(Some(automaton.start).into_iter().collect());
+
+        for &call in calls {
+            states = automaton.next_states(&states, call);
+        }
+
+        states.contains(&automaton.accept)
+    }
+
+    #[test]
+    fn sequence_accepts_calls_in_order() {
+        let symbols = [handle("a()"), handle("b()")];
+        let rule = Rule::call(&symbols[0]).then(Rule::call(&symbols[1]));
+
+        assert!(accepts(&rule, &symbols, &[0, 1]));
+        assert!(!accepts(&rule, &symbols, &[1, 0]));
+        assert!(!accepts(&rule, &symbols, &[0]));
+    }
+
+    #[test]
+    fn alternation_accepts_either_call() {
+        let symbols = [handle("a()"), handle("b()")];
+        let rule = Rule::call(&symbols[0]).or(Rule::call(&symbols[1]));
+
+        assert!(accepts(&rule, &symbols, &[0]));
+        assert!(accepts(&rule, &symbols, &[1]));
+        assert!(!accepts(&rule, &symbols, &[0, 1]));
+    }
+
+    #[test]
+    fn repetition_accepts_calls_within_bounds() {
+        let symbols = [handle("a()")];
+        let rule = Rule::call(&symbols[0]).repeat(2..=3);
+
+        assert!(!accepts(&rule, &symbols, &[0]));
+        assert!(accepts(&rule, &symbols, &[0, 0]));
+        assert!(accepts(&rule, &symbols, &[0, 0, 0]));
+        assert!(!accepts(&rule, &symbols, &[0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn unbounded_repetition_accepts_any_amount_of_calls() {
+        let symbols = [handle("a()"), handle("b()")];
+        let rule = Rule::call(&symbols[0])
+            .or(Rule::call(&symbols[1]))
+            .repeat(1..);
+
+        assert!(!accepts(&rule, &symbols, &[]));
+        assert!(accepts(&rule, &symbols, &[0, 1, 1, 0, 1]));
+    }
+
+    #[test]
+    fn optional_rule_can_be_skipped() {
+        let symbols = [handle("a()"), handle("b()")];
+        let rule = Rule::call(&symbols[0]).then(Rule::call(&symbols[1]).optional());
+
+        assert!(accepts(&rule, &symbols, &[0]));
+        assert!(accepts(&rule, &symbols, &[0, 1]));
+        assert!(!accepts(&rule, &symbols, &[0, 1, 1]));
+    }
+
+    #[test]
+    fn rule_is_displayed_as_grammar() {
+        let symbols = [
+            handle("connect()"),
+            handle("send(*)"),
+            handle("ping()"),
+            handle("disconnect()"),
+        ];
+        let rule = Rule::call(&symbols[0])
+            .then(
+                Rule::call(&symbols[1])
+                    .or(Rule::call(&symbols[2]))
+                    .repeat(..),
+            )
+            .then(Rule::call(&symbols[3]).optional());
+
+        assert_eq!(
+            "connect() (send(*) | ping())* disconnect()?",
+            rule.to_string()
+        );
+    }
+}
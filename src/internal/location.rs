@@ -0,0 +1,48 @@
+use std::fmt::{self, Display};
+use std::panic::Location;
+
+/// Returns the location of the first caller that is not annotated with `#[track_caller]`.
+/// Returns [`None`] when the compiler does not support `#[track_caller]`.
+#[cfg(rustc_has_track_caller)]
+#[track_caller]
+pub(crate) fn caller_location() -> Option<&'static Location<'static>> {
+    Some(Location::caller())
+}
+
+/// Returns the location of the first caller that is not annotated with `#[track_caller]`.
+/// Returns [`None`] when the compiler does not support `#[track_caller]`.
+#[cfg(not(rustc_has_track_caller))]
+pub(crate) fn caller_location() -> Option<&'static Location<'static>> {
+    None
+}
+
+/// Displays where an expected call was defined, if known.
+pub(crate) struct DisplayDefinedAt(pub(crate) Option<&'static Location<'static>>);
+
+impl Display for DisplayDefinedAt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(location) => write!(f, " (defined at {}:{})", location.file(), location.line()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unknown_location_is_not_displayed() {
+        assert_eq!("", DisplayDefinedAt(None).to_string());
+    }
+
+    #[cfg(rustc_has_track_caller)]
+    #[test]
+    fn caller_location_is_displayed() {
+        let location = caller_location();
+        let expected = format!(" (defined at {}:{})", file!(), line!() - 1);
+
+        assert_eq!(expected, DisplayDefinedAt(location).to_string());
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Locks the given mutex, ignoring poisoning.
+/// Mocks panic while a lock may be held, e.g. when an unexpected call is made.
+/// This must not prevent the remaining expected calls from being verified.
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Blocks until the condition holds for the value of the given mutex or the timeout elapses,
+/// ignoring poisoning. The condvar must be notified whenever the value changes.
+///
+/// Returns the locked value and whether the condition holds.
+pub(crate) fn wait_until<'a, T, F>(
+    mutex: &'a Mutex<T>,
+    condvar: &Condvar,
+    timeout: Duration,
+    mut condition: F,
+) -> (MutexGuard<'a, T>, bool)
+where
+    F: FnMut(&T) -> bool,
+{
+    let deadline = Instant::now() + timeout;
+    let mut guard = lock(mutex);
+
+    while !condition(&guard) {
+        let now = Instant::now();
+
+        if now >= deadline {
+            return (guard, false);
+        }
+
+        guard = condvar
+            .wait_timeout(guard, deadline - now)
+            .unwrap_or_else(PoisonError::into_inner)
+            .0;
+    }
+
+    (guard, true)
+}
+
+/// Notifies all threads waiting for the condvar when dropped,
+/// which also happens when the current thread panics.
+pub(crate) struct NotifyAllOnDrop<'a>(pub(crate) &'a Condvar);
+
+impl<'a> Drop for NotifyAllOnDrop<'a> {
+    fn drop(&mut self) {
+        self.0.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn wait_until_returns_when_condition_holds() {
+        let state = Arc::new((Mutex::new(0), Condvar::new()));
+
+        let handle = thread::spawn({
+            let state = state.clone();
+            move || {
+                *lock(&state.0) += 1;
+                state.1.notify_all();
+            }
+        });
+
+        let (value, condition_holds) =
+            wait_until(&state.0, &state.1, Duration::from_secs(10), |value| {
+                *value == 1
+            });
+
+        assert!(condition_holds);
+        assert_eq!(1, *value);
+
+        drop(value);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn wait_until_times_out() {
+        let mutex = Mutex::new(0);
+        let condvar = Condvar::new();
+
+        let (value, condition_holds) =
+            wait_until(&mutex, &condvar, Duration::from_millis(10), |value| {
+                *value == 1
+            });
+
+        assert!(!condition_holds);
+        assert_eq!(0, *value);
+    }
+}
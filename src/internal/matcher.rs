@@ -1,5 +1,7 @@
+use crate::internal::argument_capture::CaptureArguments;
 use crate::internal::arguments::Arguments;
-use std::fmt::{Debug, Display};
+use crate::internal::fmt::{DisplayDiff, MaybeDebug, MaybeDebugWrapper};
+use std::fmt::{self, Debug, Display};
 
 pub(crate) mod any;
 pub(crate) mod nearly_eq;
@@ -8,12 +10,111 @@ pub(crate) mod unordered_vec_eq;
 
 pub trait ArgumentMatcher<T>: Display + Debug {
     fn matches_argument(&self, input: &T) -> bool;
+
+    /// Describes how the input differs from the expected value.
+    /// Matchers that don't compare against a single value return [`None`].
+    fn mismatch(&self, _input: &T) -> Option<Mismatch> {
+        None
+    }
+
+    /// Returns `true` if this matcher matches every value.
+    /// Wildcards don't count towards the specificity of an expected call.
+    fn is_wildcard(&self) -> bool {
+        false
+    }
 }
 
-pub trait ArgumentsMatcher<'args>: Display + Debug {
+pub trait ArgumentsMatcher<'args>: Display + Debug + CaptureArguments {
     type Arguments: Arguments;
 
     fn matches_arguments(&self, input: &Self::Arguments) -> bool;
+
+    fn argument_mismatches(&self, _input: &Self::Arguments) -> Vec<ArgumentMismatch> {
+        Vec::new()
+    }
+
+    /// The number of arguments that are matched by a matcher that is not a wildcard.
+    fn specificity(&self) -> usize {
+        0
+    }
+
+    /// The number of arguments that are matched by their matcher.
+    fn matching_arguments(&self, _input: &Self::Arguments) -> usize {
+        0
+    }
+
+    /// Creates an owned copy of each argument.
+    /// Returns [`None`] if any argument can't be copied.
+    fn capture_arguments(_input: &Self::Arguments) -> Option<Vec<Self::CapturedArgument>> {
+        None
+    }
+
+    /// Recreates the arguments of a call from the arguments captured when the call was made.
+    fn restore_arguments(
+        &self,
+        _captured: &'args [Self::CapturedArgument],
+    ) -> Option<Self::Arguments> {
+        None
+    }
+}
+
+/// The pretty-printed expected and actual value of an argument that did not match.
+#[derive(Debug)]
+pub struct Mismatch {
+    expected: String,
+    actual: String,
+}
+
+impl Mismatch {
+    pub(crate) fn new<T, U>(expected: &T, actual: &U) -> Option<Self>
+    where
+        T: MaybeDebug + ?Sized,
+        U: MaybeDebug + ?Sized,
+    {
+        let expected = format!("{:#?}", MaybeDebugWrapper(&expected));
+        let actual = format!("{:#?}", MaybeDebugWrapper(&actual));
+
+        // Values that can't be formatted don't produce a meaningful diff
+        if expected == actual {
+            None
+        } else {
+            Some(Self { expected, actual })
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ArgumentMismatch {
+    argument: &'static str,
+    mismatch: Mismatch,
+}
+
+impl ArgumentMismatch {
+    pub fn new(argument: &'static str, mismatch: Mismatch) -> Self {
+        Self { argument, mismatch }
+    }
+}
+
+pub(crate) struct DisplayArgumentMismatch<'a> {
+    pub(crate) argument_mismatch: &'a ArgumentMismatch,
+    pub(crate) colored: bool,
+}
+
+impl<'a> Display for DisplayArgumentMismatch<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ArgumentMismatch { argument, mismatch } = self.argument_mismatch;
+
+        writeln!(f, "Argument `{}`:", argument)?;
+        write!(
+            f,
+            "{}",
+            DisplayDiff {
+                expected: &mismatch.expected,
+                actual: &mismatch.actual,
+                colored: self.colored,
+            }
+        )
+    }
 }
 
 #[cfg(test)]
@@ -22,6 +123,7 @@ pub(crate) use self::mock::*;
 #[cfg(test)]
 mod mock {
     use super::ArgumentsMatcher;
+    use crate::internal::argument_capture::{CaptureArguments, CapturedArgument};
     use crate::internal::arguments::ArgumentsMock;
     use std::cell::RefCell;
 
@@ -42,6 +144,10 @@ mod mock {
         }
     }
 
+    impl CaptureArguments for ArgumentsMatcherMock {
+        type CapturedArgument = CapturedArgument;
+    }
+
     impl<'args> ArgumentsMatcher<'args> for ArgumentsMatcherMock {
         type Arguments = ArgumentsMock;
 
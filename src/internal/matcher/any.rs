@@ -41,4 +41,8 @@ impl<'args, U> ArgumentMatcher<U> for AnyArgumentMatcher {
     fn matches_argument(&self, _input: &U) -> bool {
         true
     }
+
+    fn is_wildcard(&self) -> bool {
+        true
+    }
 }
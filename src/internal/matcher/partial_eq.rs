@@ -1,4 +1,4 @@
-use super::ArgumentMatcher;
+use super::{ArgumentMatcher, Mismatch};
 use crate::internal::argument::Argument;
 use crate::internal::fmt::{MaybeDebug, MaybeDebugWrapper};
 use nameof::name_of;
@@ -96,6 +96,14 @@ where
     fn matches_argument(&self, input: &U) -> bool {
         &self.value == input
     }
+
+    fn mismatch(&self, input: &U) -> Option<Mismatch> {
+        if self.matches_argument(input) {
+            None
+        } else {
+            Mismatch::new(&self.value, input)
+        }
+    }
 }
 
 pub struct OwnedPartialEqArgumentMatcher<T>
@@ -121,6 +129,14 @@ where
     fn matches_argument(&self, input: &&U) -> bool {
         &self.value == *input
     }
+
+    fn mismatch(&self, input: &&U) -> Option<Mismatch> {
+        if self.matches_argument(input) {
+            None
+        } else {
+            Mismatch::new(&self.value, *input)
+        }
+    }
 }
 
 impl<T> Debug for OwnedPartialEqArgumentMatcher<T> {
@@ -130,3 +146,60 @@ impl<T> Debug for OwnedPartialEqArgumentMatcher<T> {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::internal::matcher::{ArgumentMismatch, DisplayArgumentMismatch};
+
+    #[derive(Debug, PartialEq)]
+    struct Person {
+        name: String,
+        age: u8,
+    }
+
+    fn person(age: u8) -> Person {
+        Person {
+            name: String::from("Name"),
+            age,
+        }
+    }
+
+    #[test]
+    fn matching_value_has_no_mismatch() {
+        let factory = Argument::internal_new();
+        let matcher = factory.partial_eq(person(30));
+
+        assert!(matcher.mismatch(&person(30)).is_none());
+    }
+
+    #[cfg(rustc_is_nightly)]
+    #[test]
+    fn mismatching_value_has_mismatch() {
+        let factory = Argument::internal_new();
+        let matcher = factory.partial_eq(person(30));
+        let mismatch = matcher.mismatch(&person(31)).unwrap();
+
+        assert_eq!(format!("{:#?}", person(30)), mismatch.expected);
+        assert_eq!(format!("{:#?}", person(31)), mismatch.actual);
+    }
+
+    #[test]
+    fn mismatch_is_displayed_as_diff() {
+        let mismatch = Mismatch {
+            expected: format!("{:#?}", person(30)),
+            actual: format!("{:#?}", person(31)),
+        };
+        let argument_mismatch = ArgumentMismatch::new("person", mismatch);
+
+        assert_eq!(
+            "Argument `person`:\n  Person {\n      name: \"Name\",\n-     age: 30,\n+     age: \
+             31,\n  }\n",
+            DisplayArgumentMismatch {
+                argument_mismatch: &argument_mismatch,
+                colored: false,
+            }
+            .to_string()
+        );
+    }
+}
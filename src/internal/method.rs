@@ -1,13 +1,32 @@
-use crate::internal::matcher::ArgumentsMatcher;
+use crate::internal::ambiguity_resolution::AmbiguityResolution;
+use crate::internal::arguments::Arguments;
+use crate::internal::call_number::next_call_number;
+use crate::internal::call_verification::CallVerification;
+use crate::internal::default_return_value::DefaultReturnValueFn;
+use crate::internal::expectation::ExpectationHandle;
+use crate::internal::expected_calls::ExpectedCalls;
+use crate::internal::fmt::{use_colors, DebugFn, DisplayTimes};
+use crate::internal::grammar::{Grammar, GrammarError};
+use crate::internal::lock::{lock, wait_until, NotifyAllOnDrop};
+use crate::internal::matcher::{ArgumentMismatch, ArgumentsMatcher, DisplayArgumentMismatch};
 use crate::internal::method_call::{MethodCall, MethodCallBuilder};
+use crate::internal::mock_future::MockFuture;
+use crate::internal::recorded_call::RecordedCall;
+use crate::internal::return_value::{self, ReturnValue};
+use crate::internal::sequence::SequenceError;
+use crate::internal::states::States;
+use crate::internal::strictness::Strictness;
+use crate::internal::verification_report::{MethodVerificationFailure, MockVerificationReport};
 use nameof::name_of;
 use std::fmt::{self, Debug, Display};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
 
 #[cfg(rustc_is_nightly)]
 const FOOTER: &str = "";
 #[cfg(not(rustc_is_nightly))]
-const FOOTER: &str = "\n\n----\nSome mockiato messages may not be displayed correctly. Re-run \
-                      with nightly rust to see full error messages.\n----\n\n";
+const FOOTER: &str = "\n\n----\nArgument matchers may not be displayed correctly. Re-run with \
+                      nightly rust to see full error messages.\n----\n\n";
 
 #[derive(Clone, Debug)]
 enum ExpectedCallOrder {
@@ -21,7 +40,39 @@ where
 {
     name: &'static str,
     calls: Vec<MethodCall<'mock, A, R>>,
+    /// Calls that are allowed any number of times and are never verified.
+    stubs: Vec<MethodCall<'mock, A, R>>,
     call_order: ExpectedCallOrder,
+    ambiguity_resolution: AmbiguityResolution,
+    strictness: Strictness,
+    debug_return_value: DebugFn<R>,
+    default_return_value: Option<Arc<ReturnValue<R>>>,
+    nice_return_value: Option<DefaultReturnValueFn<R>>,
+    recorded_calls: Mutex<Vec<Recording<A::CapturedArgument>>>,
+    /// Notified whenever a call was recorded and its return value was produced.
+    call_recorded: Condvar,
+    /// Failures of calls that were made while deferring failures.
+    deferred_failures: Mutex<Vec<String>>,
+    /// Held while a call is accepted and counted, so that calls made concurrently
+    /// can't exceed the maximum number of calls of an expected call.
+    accepting_call: Mutex<()>,
+}
+
+/// A recorded call together with owned copies of its arguments,
+/// which are used to verify calls after they were made.
+#[derive(Debug)]
+struct Recording<C> {
+    call: RecordedCall,
+    arguments: Option<Arc<Vec<C>>>,
+}
+
+impl<C> Clone for Recording<C> {
+    fn clone(&self) -> Self {
+        Self {
+            call: self.call.clone(),
+            arguments: self.arguments.clone(),
+        }
+    }
 }
 
 impl<'mock, A, R> Debug for Method<'mock, A, R>
@@ -32,7 +83,15 @@ where
         f.debug_struct(name_of!(type Method<'mock, A, R>))
             .field(name_of!(name in Self), &self.name)
             .field(name_of!(calls in Self), &self.calls)
+            .field(name_of!(stubs in Self), &self.stubs)
             .field(name_of!(call_order in Self), &self.call_order)
+            .field(
+                name_of!(ambiguity_resolution in Self),
+                &self.ambiguity_resolution,
+            )
+            .field(name_of!(strictness in Self), &self.strictness)
+            .field(name_of!(recorded_calls in Self), &self.recorded_calls)
+            .field(name_of!(deferred_failures in Self), &self.deferred_failures)
             .finish()
     }
 }
@@ -45,7 +104,17 @@ where
         Self {
             name: self.name,
             calls: self.calls.clone(),
+            stubs: self.stubs.clone(),
             call_order: self.call_order.clone(),
+            ambiguity_resolution: self.ambiguity_resolution,
+            strictness: self.strictness,
+            debug_return_value: self.debug_return_value,
+            default_return_value: self.default_return_value.clone(),
+            nice_return_value: self.nice_return_value,
+            recorded_calls: Mutex::new(lock(&self.recorded_calls).clone()),
+            call_recorded: Condvar::new(),
+            deferred_failures: Mutex::new(lock(&self.deferred_failures).clone()),
+            accepting_call: Mutex::default(),
         }
     }
 }
@@ -54,59 +123,397 @@ impl<'mock, A, R> Method<'mock, A, R>
 where
     A: for<'args> ArgumentsMatcher<'args>,
 {
-    pub fn new(name: &'static str) -> Self {
+    /// Creates a new method.
+    /// The [`DebugFn`] is used to display return values.
+    pub fn new(name: &'static str, debug_return_value: DebugFn<R>) -> Self {
         Self {
             name,
             calls: Vec::new(),
+            stubs: Vec::new(),
             call_order: ExpectedCallOrder::Unordered,
+            ambiguity_resolution: AmbiguityResolution::default(),
+            strictness: Strictness::default(),
+            debug_return_value,
+            default_return_value: None,
+            nice_return_value: None,
+            recorded_calls: Mutex::default(),
+            call_recorded: Condvar::new(),
+            deferred_failures: Mutex::default(),
+            accepting_call: Mutex::default(),
         }
     }
 
+    /// Uses the value created by the given function as return value
+    /// for expected calls that don't specify a return value.
+    pub fn with_default_return_value(
+        mut self,
+        default_return_value: Option<DefaultReturnValueFn<R>>,
+    ) -> Self {
+        let debug_return_value = self.debug_return_value;
+        self.default_return_value = default_return_value.map(|default_return_value| {
+            Arc::new(ReturnValue::DefaultValue(return_value::DefaultValue(
+                default_return_value,
+                debug_return_value,
+            )))
+        });
+        self
+    }
+
+    /// Uses the value created by the given function as return value
+    /// for unexpected calls while this method is nice.
+    pub fn with_nice_return_value(
+        mut self,
+        nice_return_value: Option<DefaultReturnValueFn<R>>,
+    ) -> Self {
+        self.nice_return_value = nice_return_value;
+        self
+    }
+
+    /// Adds an expected call to this method.
+    /// The location of the caller is recorded to display where the call was defined.
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
     pub fn add_expected_call(&mut self, matcher: A) -> MethodCallBuilder<'mock, '_, A, R> {
-        let call = MethodCall::new(matcher);
+        let call = MethodCall::new(
+            matcher,
+            self.debug_return_value,
+            self.default_return_value.clone(),
+        );
 
         self.calls.push(call);
 
-        MethodCallBuilder::new(self.calls.last_mut().unwrap())
+        MethodCallBuilder::new(self.name, self.calls.last_mut().unwrap())
+    }
+
+    /// Adds a stub to this method.
+    /// Stubs can be called any number of times and are used when no expected call matches.
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
+    pub fn add_stub(&mut self, matcher: A) -> MethodCallBuilder<'mock, '_, A, R> {
+        let stub = MethodCall::new(
+            matcher,
+            self.debug_return_value,
+            self.default_return_value.clone(),
+        );
+
+        self.stubs.push(stub);
+
+        let mut builder = MethodCallBuilder::new(self.name, self.stubs.last_mut().unwrap());
+        builder.times(..);
+        builder
     }
 
     pub fn expect_method_calls_in_order(&mut self) {
         self.call_order = ExpectedCallOrder::Sequentially;
     }
 
+    /// Configures which expected call is used when a call matches more than one expected call.
+    pub fn set_ambiguity_resolution(&mut self, ambiguity_resolution: AmbiguityResolution) {
+        self.ambiguity_resolution = ambiguity_resolution;
+    }
+
+    /// Configures how calls that don't match any expected call are handled.
+    pub fn set_strictness(&mut self, strictness: Strictness) {
+        self.strictness = strictness;
+    }
+
+    /// Returns the calls that did not match any expected call while this method was nice
+    /// or deferred failures, together with their call number.
+    pub fn unexpected_calls(&self) -> Vec<(usize, String)> {
+        lock(&self.recorded_calls)
+            .iter()
+            .map(|recording| &recording.call)
+            .filter(|call| call.matched_expectation().is_none())
+            .map(|call| (call.sequence_number(), call.to_string()))
+            .collect()
+    }
+
+    /// Returns all calls that were made to this method.
+    pub fn recorded_calls(&self) -> Vec<RecordedCall> {
+        lock(&self.recorded_calls)
+            .iter()
+            .map(|recording| recording.call.clone())
+            .collect()
+    }
+
+    /// Blocks until at least the given number of calls were made to this method,
+    /// panicking if this does not happen before the timeout elapses.
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
+    pub fn wait_for_calls(&self, count: u64, timeout: Duration) {
+        let (recordings, enough_calls) = wait_until(
+            &self.recorded_calls,
+            &self.call_recorded,
+            timeout,
+            |recordings| recordings.len() as u64 >= count,
+        );
+
+        if !enough_calls {
+            let err = WaitTimeoutError {
+                name: self.name,
+                count,
+                timeout,
+                calls: recordings
+                    .iter()
+                    .map(|recording| recording.call.clone())
+                    .collect(),
+            };
+            drop(recordings);
+
+            panic!("\n\n{}\n", err);
+        }
+    }
+
+    /// Creates a verification of the recorded calls matching the given matcher.
+    pub fn verify_calls(&self, matcher: A) -> CallVerification<'mock, '_, A, R> {
+        CallVerification::new(self, matcher)
+    }
+
+    /// Panics if the number of recorded calls matching the given matcher
+    /// is not in the expected range.
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
+    pub(crate) fn verify_recorded_calls_unwrap(&self, matcher: &A, expected_calls: &ExpectedCalls) {
+        self.verify_recorded_calls(matcher, expected_calls)
+            .unwrap_or_else(|err| panic!("\n\n{}{}\n", err, FOOTER))
+    }
+
+    fn verify_recorded_calls(
+        &self,
+        matcher: &A,
+        expected_calls: &ExpectedCalls,
+    ) -> Result<(), RecordedCallsVerificationError> {
+        let recordings = lock(&self.recorded_calls);
+
+        let mut matching_calls = Vec::new();
+        let mut mismatching_calls = Vec::new();
+        let mut unverifiable_calls = Vec::new();
+
+        for recording in recordings.iter() {
+            let arguments = recording
+                .arguments
+                .as_ref()
+                .and_then(|captured| matcher.restore_arguments(captured));
+
+            match arguments {
+                Some(ref arguments) if matcher.matches_arguments(arguments) => {
+                    matching_calls.push(recording.call.clone());
+                }
+                Some(ref arguments) => mismatching_calls.push(MismatchingCall {
+                    call: recording.call.clone(),
+                    matching_arguments: matcher.matching_arguments(arguments),
+                    differences: display_argument_mismatches(
+                        &matcher.argument_mismatches(arguments),
+                    ),
+                }),
+                None => unverifiable_calls.push(recording.call.clone()),
+            }
+        }
+
+        if !unverifiable_calls.is_empty() {
+            return Err(RecordedCallsVerificationError::NotRecorded {
+                name: self.name,
+                calls: unverifiable_calls,
+            });
+        }
+
+        let actual_calls = matching_calls.len() as u64;
+
+        if expected_calls.contains(actual_calls) {
+            return Ok(());
+        }
+
+        let too_many_calls = expected_calls
+            .max_value()
+            .map_or(false, |max_value| actual_calls > max_value);
+
+        let closest_calls = if too_many_calls {
+            ClosestCalls::Matching(matching_calls)
+        } else {
+            let max_matching_arguments = mismatching_calls
+                .iter()
+                .map(|call| call.matching_arguments)
+                .max();
+
+            ClosestCalls::Mismatching(
+                mismatching_calls
+                    .into_iter()
+                    .filter(|call| Some(call.matching_arguments) == max_matching_arguments)
+                    .collect(),
+            )
+        };
+
+        Err(RecordedCallsVerificationError::UnexpectedNumberOfCalls {
+            name: self.name,
+            matcher: matcher.to_string(),
+            expected_calls: expected_calls.clone(),
+            actual_calls,
+            closest_calls,
+        })
+    }
+
+    fn record_call(
+        &self,
+        arguments: &<A as ArgumentsMatcher<'_>>::Arguments,
+        matched_expectation: Option<&MethodCall<'mock, A, R>>,
+    ) {
+        lock(&self.recorded_calls).push(Recording {
+            call: RecordedCall::new(
+                self.name,
+                arguments.debug_arguments(),
+                matched_expectation.map(|method_call| method_call.matcher().to_string()),
+                next_call_number(),
+            ),
+            arguments: A::capture_arguments(arguments).map(Arc::new),
+        });
+    }
+
     pub fn call_unwrap<'a>(&'a self, arguments: <A as ArgumentsMatcher<'a>>::Arguments) -> R {
+        let _notify = NotifyAllOnDrop(&self.call_recorded);
+
         self.call(arguments)
-            .unwrap_or_else(|err| panic!("\n\n{}{}\n", err, FOOTER))
+            .unwrap_or_else(|err| self.handle_call_error(err))
+    }
+
+    /// Calls this async method, returning a future that resolves to the return value.
+    pub fn call_async_unwrap<'a>(
+        &'a self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> MockFuture<R> {
+        let _notify = NotifyAllOnDrop(&self.call_recorded);
+
+        self.call_async(arguments)
+            .unwrap_or_else(|err| MockFuture::ready(self.handle_call_error(err)))
+    }
+
+    fn handle_call_error<'a>(&'a self, err: CallError<'mock, 'a, A, R>) -> R {
+        match (self.strictness, self.nice_return_value) {
+            (Strictness::Nice, Some(nice_return_value)) => {
+                if let Some(arguments) = err.unexpected_arguments() {
+                    self.record_call(arguments, None);
+                    return nice_return_value();
+                }
+            }
+            (Strictness::Deferred, nice_return_value) => {
+                self.defer_failure(&err);
+
+                if let Some(nice_return_value) = nice_return_value {
+                    self.record_call(&err.into_arguments(), None);
+                    return nice_return_value();
+                }
+            }
+            _ => {}
+        }
+
+        panic!("\n\n{}{}\n", err, FOOTER)
+    }
+
+    /// Records the failure of a call, so that it is reported when this method is verified.
+    fn defer_failure(&self, err: &CallError<'mock, '_, A, R>) {
+        let thread = std::thread::current();
+        let failure = format!(
+            "On thread '{}':\n{}",
+            thread.name().unwrap_or("<unnamed>"),
+            err
+        );
+
+        lock(&self.deferred_failures).push(failure);
     }
 
     pub fn verify_unwrap(&self) {
         self.verify()
-            .unwrap_or_else(|err| panic!("{}{}", err, FOOTER))
+            .unwrap_or_else(|failure| panic!("{}{}", failure, FOOTER))
+    }
+
+    /// Adds a failure to the report if the expected calls
+    /// were not called the expected number of times.
+    pub fn verify_into(&self, report: &mut MockVerificationReport) {
+        if let Err(failure) = self.verify() {
+            report.add(failure);
+        }
+    }
+
+    /// Removes all expected calls and stubs from this method
+    /// and no longer expects the calls to be made in order.
+    /// The recorded calls and the configuration of strictness, ambiguity resolution
+    /// and return values are kept.
+    pub fn clear_expected_calls(&mut self) {
+        self.calls.clear();
+        self.stubs.clear();
+        self.call_order = ExpectedCallOrder::Unordered;
     }
 
     fn call<'a>(
         &'a self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
     ) -> Result<R, CallError<'mock, 'a, A, R>> {
-        match self.call_order {
+        self.accept_call(arguments)
+            .map(|(method_call, arguments)| method_call.call(self.name, arguments))
+    }
+
+    fn call_async<'a>(
+        &'a self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> Result<MockFuture<R>, CallError<'mock, 'a, A, R>> {
+        self.accept_call(arguments).map(|(method_call, arguments)| {
+            MockFuture::new(
+                method_call.pending_polls(),
+                method_call.call(self.name, arguments),
+            )
+        })
+    }
+
+    /// Finds the expected call accepting the given call and records the call.
+    fn accept_call<'a>(
+        &'a self,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> Result<AcceptedCall<'mock, 'a, A, R>, CallError<'mock, 'a, A, R>> {
+        let _accepting_call = lock(&self.accepting_call);
+
+        let result = match self.call_order {
             ExpectedCallOrder::Sequentially => {
                 self.handle_call_with_sequentially_ordered_expectations(arguments)
             }
             ExpectedCallOrder::Unordered => self.handle_call_with_unordered_expectations(arguments),
+        };
+
+        result.or_else(|err| self.call_stub(err))
+    }
+
+    /// Uses the most recently added matching stub for calls that did not match any expected call.
+    fn call_stub<'a>(
+        &'a self,
+        err: CallError<'mock, 'a, A, R>,
+    ) -> Result<AcceptedCall<'mock, 'a, A, R>, CallError<'mock, 'a, A, R>> {
+        let matching_stub = err.unexpected_arguments().and_then(|arguments| {
+            self.stubs.iter().rev().find(|stub| {
+                stub.matches_expected_arguments(arguments)
+                    && stub.is_in_expected_state()
+                    && stub.accepts_more_calls()
+            })
+        });
+
+        match matching_stub {
+            Some(stub) => self.call_in_order(stub, err.into_arguments()),
+            None => Err(err),
         }
     }
 
     fn handle_call_with_sequentially_ordered_expectations<'a>(
         &'a self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
-    ) -> Result<R, CallError<'mock, 'a, A, R>> {
+    ) -> Result<AcceptedCall<'mock, 'a, A, R>, CallError<'mock, 'a, A, R>> {
         let matching_method_call = self.calls.iter().find(|call| call.accepts_more_calls());
 
         match matching_method_call {
             Some(matching_method_call)
                 if matching_method_call.matches_expected_arguments(&arguments) =>
             {
-                Ok(matching_method_call.call(arguments))
+                if matching_method_call.is_in_expected_state() {
+                    self.call_in_order(matching_method_call, arguments)
+                } else {
+                    Err(CallError::UnexpectedState(
+                        arguments,
+                        self,
+                        vec![matching_method_call],
+                    ))
+                }
             }
             _ => Err(CallError::NoMatching(arguments, self)),
         }
@@ -115,44 +522,184 @@ where
     fn handle_call_with_unordered_expectations<'a>(
         &'a self,
         arguments: <A as ArgumentsMatcher<'a>>::Arguments,
-    ) -> Result<R, CallError<'mock, 'a, A, R>> {
-        let matching_method_calls = self
+    ) -> Result<AcceptedCall<'mock, 'a, A, R>, CallError<'mock, 'a, A, R>> {
+        let (matching_method_calls, method_calls_in_other_states) = self
             .calls
             .iter()
             .filter(|call| call.matches_expected_arguments(&arguments))
-            .collect::<Vec<_>>();
+            .partition::<Vec<_>, _>(|call| call.is_in_expected_state());
 
         match matching_method_calls.len() {
+            0 if !method_calls_in_other_states.is_empty() => Err(CallError::UnexpectedState(
+                arguments,
+                self,
+                method_calls_in_other_states,
+            )),
             0 => Err(CallError::NoMatching(arguments, self)),
             1 => {
                 let expected_call = matching_method_calls.first().unwrap();
-                if expected_call.accepts_more_calls() {
-                    Ok(expected_call.call(arguments))
-                } else {
-                    Err(CallError::NoMatching(arguments, self))
-                }
+                self.call_if_accepting_more_calls(expected_call, arguments)
             }
-            _ => Err(CallError::MoreThanOneMatching(
+            _ => self.resolve_ambiguous_call(matching_method_calls, arguments),
+        }
+    }
+
+    fn resolve_ambiguous_call<'a>(
+        &'a self,
+        matching_method_calls: Vec<&'a MethodCall<'mock, A, R>>,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> Result<AcceptedCall<'mock, 'a, A, R>, CallError<'mock, 'a, A, R>> {
+        match self.ambiguity_resolution {
+            AmbiguityResolution::Strict => Err(CallError::MoreThanOneMatching(
                 arguments,
                 self,
                 matching_method_calls,
             )),
+            AmbiguityResolution::MostRecentlyDefined => {
+                let expected_call = matching_method_calls.last().unwrap();
+                self.call_if_accepting_more_calls(expected_call, arguments)
+            }
+            AmbiguityResolution::FirstUnsaturated => {
+                match matching_method_calls
+                    .into_iter()
+                    .find(|call| call.accepts_more_calls())
+                {
+                    Some(expected_call) => self.call_in_order(expected_call, arguments),
+                    None => Err(CallError::NoMatching(arguments, self)),
+                }
+            }
+            AmbiguityResolution::MostSpecific => {
+                let unsaturated_method_calls: Vec<_> = matching_method_calls
+                    .into_iter()
+                    .filter(|call| call.accepts_more_calls())
+                    .collect();
+                let highest_specificity = unsaturated_method_calls
+                    .iter()
+                    .map(|call| call.specificity())
+                    .max();
+                let most_specific_method_calls: Vec<_> = unsaturated_method_calls
+                    .into_iter()
+                    .filter(|call| Some(call.specificity()) == highest_specificity)
+                    .collect();
+
+                match most_specific_method_calls.len() {
+                    0 => Err(CallError::NoMatching(arguments, self)),
+                    1 => self.call_in_order(most_specific_method_calls[0], arguments),
+                    _ => Err(CallError::MoreThanOneMatching(
+                        arguments,
+                        self,
+                        most_specific_method_calls,
+                    )),
+                }
+            }
+        }
+    }
+
+    fn call_if_accepting_more_calls<'a>(
+        &'a self,
+        method_call: &'a MethodCall<'mock, A, R>,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> Result<AcceptedCall<'mock, 'a, A, R>, CallError<'mock, 'a, A, R>> {
+        if method_call.accepts_more_calls() {
+            self.call_in_order(method_call, arguments)
+        } else {
+            Err(CallError::NoMatching(arguments, self))
+        }
+    }
+
+    fn call_in_order<'a>(
+        &'a self,
+        method_call: &'a MethodCall<'mock, A, R>,
+        arguments: <A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> Result<AcceptedCall<'mock, 'a, A, R>, CallError<'mock, 'a, A, R>> {
+        if let Some(prerequisite) = method_call.unsatisfied_prerequisite() {
+            return Err(CallError::PrerequisiteNotSatisfied(
+                arguments,
+                self,
+                method_call,
+                prerequisite,
+            ));
+        }
+
+        // The grammars stay locked until the call was accepted,
+        // so that concurrent calls cannot advance them in between
+        let grammars = method_call.grammars();
+        let mut locked_grammars = Grammar::lock_all(&grammars);
+
+        if let Err(error) = locked_grammars.check() {
+            return Err(CallError::NotInGrammar(arguments, self, method_call, error));
         }
+
+        if let Err(error) = method_call.try_advance_sequence() {
+            return Err(CallError::OutOfSequence(
+                arguments,
+                self,
+                method_call,
+                error,
+            ));
+        }
+
+        locked_grammars.advance();
+        method_call.accept();
+        drop(locked_grammars);
+
+        self.record_call(&arguments, Some(method_call));
+        Ok((method_call, arguments))
     }
 
-    fn verify(&self) -> Result<(), VerificationError<'mock, '_, A, R>> {
-        if self
+    fn verify(&self) -> Result<(), MethodVerificationFailure> {
+        let unsatisfied = self
             .calls
             .iter()
-            .any(|method_call| !method_call.was_called_expected_number_of_times())
-        {
-            Err(VerificationError { method: self })
+            .any(|method_call| !method_call.was_called_expected_number_of_times());
+        let deferred_failures = lock(&self.deferred_failures).clone();
+        let incomplete_grammars = self.incomplete_grammars();
+
+        if unsatisfied || !deferred_failures.is_empty() || !incomplete_grammars.is_empty() {
+            Err(MethodVerificationFailure::new(
+                self.name,
+                self.calls.iter().map(MethodCall::report).collect(),
+                deferred_failures,
+                incomplete_grammars,
+            ))
         } else {
             Ok(())
         }
     }
+
+    /// Returns the grammars the expected calls of this method are part of
+    /// that were not completed by the calls.
+    fn incomplete_grammars(&self) -> Vec<String> {
+        let mut grammars: Vec<Grammar> = Vec::new();
+
+        for (grammar, _) in self
+            .calls
+            .iter()
+            .chain(&self.stubs)
+            .flat_map(MethodCall::grammars)
+        {
+            if !grammars
+                .iter()
+                .any(|known_grammar| known_grammar.is_same(&grammar))
+            {
+                grammars.push(grammar);
+            }
+        }
+
+        grammars
+            .iter()
+            .filter_map(|grammar| grammar.verify().err())
+            .map(|error| error.to_string())
+            .collect()
+    }
 }
 
+/// An expected call that accepted a call, together with the arguments of the call.
+type AcceptedCall<'mock, 'a, A, R> = (
+    &'a MethodCall<'mock, A, R>,
+    <A as ArgumentsMatcher<'a>>::Arguments,
+);
+
 #[derive(Debug)]
 enum CallError<'mock, 'a, A, R>
 where
@@ -167,6 +714,55 @@ where
         &'a Method<'mock, A, R>,
         Vec<&'a MethodCall<'mock, A, R>>,
     ),
+    OutOfSequence(
+        <A as ArgumentsMatcher<'a>>::Arguments,
+        &'a Method<'mock, A, R>,
+        &'a MethodCall<'mock, A, R>,
+        SequenceError,
+    ),
+    PrerequisiteNotSatisfied(
+        <A as ArgumentsMatcher<'a>>::Arguments,
+        &'a Method<'mock, A, R>,
+        &'a MethodCall<'mock, A, R>,
+        &'a ExpectationHandle,
+    ),
+    NotInGrammar(
+        <A as ArgumentsMatcher<'a>>::Arguments,
+        &'a Method<'mock, A, R>,
+        &'a MethodCall<'mock, A, R>,
+        GrammarError,
+    ),
+    UnexpectedState(
+        <A as ArgumentsMatcher<'a>>::Arguments,
+        &'a Method<'mock, A, R>,
+        Vec<&'a MethodCall<'mock, A, R>>,
+    ),
+}
+
+impl<'mock, 'a, A, R> CallError<'mock, 'a, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    /// Returns the arguments of the call if it did not match any expected call.
+    fn unexpected_arguments(&self) -> Option<&<A as ArgumentsMatcher<'a>>::Arguments> {
+        match self {
+            CallError::NoMatching(arguments, _) | CallError::UnexpectedState(arguments, ..) => {
+                Some(arguments)
+            }
+            _ => None,
+        }
+    }
+
+    fn into_arguments(self) -> <A as ArgumentsMatcher<'a>>::Arguments {
+        match self {
+            CallError::NoMatching(arguments, ..)
+            | CallError::MoreThanOneMatching(arguments, ..)
+            | CallError::OutOfSequence(arguments, ..)
+            | CallError::PrerequisiteNotSatisfied(arguments, ..)
+            | CallError::NotInGrammar(arguments, ..)
+            | CallError::UnexpectedState(arguments, ..) => arguments,
+        }
+    }
 }
 
 impl<'mock, 'a, A, R> Display for CallError<'mock, 'a, A, R>
@@ -185,6 +781,15 @@ where
                         f,
                         "The following calls were expected:\n{}",
                         DisplayCalls(&method.calls.iter().collect::<Vec<_>>())
+                    )?;
+
+                    write!(
+                        f,
+                        "{}",
+                        DisplayMismatches {
+                            calls: &method.calls,
+                            arguments,
+                        }
                     )
                 }
             }
@@ -195,37 +800,186 @@ where
                 arguments,
                 DisplayCalls(calls)
             ),
+            CallError::OutOfSequence(arguments, method, call, error) => writeln!(
+                f,
+                "The call {}{} was made out of sequence.\nThe following call was expected \
+                 next:\n{}\nThe call matches:\n{}",
+                method.name, arguments, error, call
+            ),
+            CallError::PrerequisiteNotSatisfied(arguments, method, call, prerequisite) => writeln!(
+                f,
+                "The call {}{} was made before the following expected call was \
+                 satisfied:\n{}\nThe call matches:\n{}",
+                method.name, arguments, prerequisite, call
+            ),
+            CallError::NotInGrammar(arguments, method, call, error) => writeln!(
+                f,
+                "The call {}{} does not match the grammar.\n{}The call matches:\n{}",
+                method.name, arguments, error, call
+            ),
+            CallError::UnexpectedState(arguments, method, calls) => writeln!(
+                f,
+                "The call {}{} was not expected in the current state.\nCurrent state:\n{}The \
+                 following calls match, but are expected in a different state:\n{}",
+                method.name,
+                arguments,
+                DisplayCurrentStates(calls),
+                DisplayCalls(calls)
+            ),
         }
     }
 }
 
 #[derive(Debug)]
-struct VerificationError<'mock, 'a, A, R>
-where
-    A: for<'args> ArgumentsMatcher<'args>,
-{
-    method: &'a Method<'mock, A, R>,
+struct WaitTimeoutError {
+    name: &'static str,
+    count: u64,
+    timeout: Duration,
+    calls: Vec<RecordedCall>,
 }
 
-impl<'mock, 'a, A, R> Display for VerificationError<'mock, 'a, A, R>
-where
-    A: for<'args> ArgumentsMatcher<'args>,
-{
+impl Display for WaitTimeoutError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(
             f,
-            "The expected calls for {} were not satisified.",
-            self.method.name
+            "Timed out after {:?} waiting for {} to be called at least {}.",
+            self.timeout,
+            self.name,
+            DisplayTimes(self.count)
         )?;
 
-        for call in &self.method.calls {
-            writeln!(f, "{}", call)?;
+        if self.calls.is_empty() {
+            writeln!(f, "No calls to {} were made.", self.name)
+        } else {
+            writeln!(f, "The following calls were made:")?;
+
+            for call in &self.calls {
+                writeln!(f, "{}", call)?;
+            }
+
+            Ok(())
         }
+    }
+}
 
-        Ok(())
+#[derive(Debug)]
+enum RecordedCallsVerificationError {
+    NotRecorded {
+        name: &'static str,
+        calls: Vec<RecordedCall>,
+    },
+    UnexpectedNumberOfCalls {
+        name: &'static str,
+        matcher: String,
+        expected_calls: ExpectedCalls,
+        actual_calls: u64,
+        closest_calls: ClosestCalls,
+    },
+}
+
+/// The recorded calls that are displayed when the verification of recorded calls fails.
+#[derive(Debug)]
+enum ClosestCalls {
+    /// The matching calls are displayed when there are more matching calls than expected.
+    Matching(Vec<RecordedCall>),
+    /// The calls that match the most arguments are displayed otherwise.
+    Mismatching(Vec<MismatchingCall>),
+}
+
+#[derive(Debug)]
+struct MismatchingCall {
+    call: RecordedCall,
+    matching_arguments: usize,
+    differences: String,
+}
+
+impl Display for RecordedCallsVerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordedCallsVerificationError::NotRecorded { name, calls } => {
+                writeln!(
+                    f,
+                    "The calls to {} can't be verified, because the arguments of the following \
+                     calls were not recorded:",
+                    name
+                )?;
+
+                for call in calls {
+                    writeln!(f, "{}", call)?;
+                }
+
+                writeln!(
+                    f,
+                    "Only arguments implementing `Clone` and references to types implementing \
+                     `ToOwned` are recorded."
+                )
+            }
+            RecordedCallsVerificationError::UnexpectedNumberOfCalls {
+                name,
+                matcher,
+                expected_calls,
+                actual_calls,
+                closest_calls,
+            } => {
+                writeln!(
+                    f,
+                    "The call {} was expected to be made {}, but was made {}.",
+                    matcher,
+                    expected_calls,
+                    DisplayTimes(*actual_calls)
+                )?;
+
+                match closest_calls {
+                    ClosestCalls::Matching(calls) => {
+                        writeln!(f, "The following recorded calls match:")?;
+
+                        for call in calls {
+                            writeln!(f, "{}", call)?;
+                        }
+
+                        Ok(())
+                    }
+                    ClosestCalls::Mismatching(calls) if calls.is_empty() => {
+                        if *actual_calls == 0 {
+                            writeln!(f, "No calls to {} were recorded.", name)
+                        } else {
+                            Ok(())
+                        }
+                    }
+                    ClosestCalls::Mismatching(calls) => {
+                        writeln!(f, "The following recorded calls came closest to matching:")?;
+
+                        for call in calls {
+                            writeln!(f, "{}", call.call)?;
+                        }
+
+                        for call in calls.iter().filter(|call| !call.differences.is_empty()) {
+                            write!(f, "\nDifferences to {}:\n{}", call.call, call.differences)?;
+                        }
+
+                        Ok(())
+                    }
+                }
+            }
+        }
     }
 }
 
+fn display_argument_mismatches(argument_mismatches: &[ArgumentMismatch]) -> String {
+    let colored = use_colors();
+
+    argument_mismatches
+        .iter()
+        .map(|argument_mismatch| {
+            DisplayArgumentMismatch {
+                argument_mismatch,
+                colored,
+            }
+            .to_string()
+        })
+        .collect()
+}
+
 struct DisplayCalls<'mock, 'a, A, R>(&'a [&'a MethodCall<'mock, A, R>]);
 
 impl<'mock, 'a, A, R> Display for DisplayCalls<'mock, 'a, A, R>
@@ -241,19 +995,94 @@ where
     }
 }
 
+/// Displays the current state of each state machine guarding the given calls
+struct DisplayCurrentStates<'mock, 'a, A, R>(&'a [&'a MethodCall<'mock, A, R>]);
+
+impl<'mock, 'a, A, R> Display for DisplayCurrentStates<'mock, 'a, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut states: Vec<&States> = Vec::new();
+
+        for guard in self.0.iter().filter_map(|call| call.guard()) {
+            if !states.iter().any(|states| states.is_same(guard.states())) {
+                states.push(guard.states());
+            }
+        }
+
+        for states in states {
+            writeln!(f, "{}", states)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays how the arguments of a call differ from each expected call
+struct DisplayMismatches<'mock, 'a, 'b, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    calls: &'b [MethodCall<'mock, A, R>],
+    arguments: &'b <A as ArgumentsMatcher<'a>>::Arguments,
+}
+
+impl<'mock, 'a, 'b, A, R> Display for DisplayMismatches<'mock, 'a, 'b, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let colored = use_colors();
+
+        for call in self.calls {
+            let argument_mismatches = call.argument_mismatches(self.arguments);
+
+            if argument_mismatches.is_empty() {
+                continue;
+            }
+
+            writeln!(f, "Differences to {}:", call.matcher())?;
+
+            for argument_mismatch in &argument_mismatches {
+                write!(
+                    f,
+                    "{}",
+                    DisplayArgumentMismatch {
+                        argument_mismatch,
+                        colored,
+                    }
+                )?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::internal::arguments::ArgumentsMock;
     use crate::internal::matcher::ArgumentsMatcherMock;
 
+    fn debug_fn<T>(_: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mock")
+    }
+
     #[test]
     fn call_errors_if_more_than_one_call_matches() {
-        let mut method = Method::<_, ()>::new("test");
+        let mut method = Method::<_, ()>::new("test", debug_fn);
 
-        method.add_expected_call(ArgumentsMatcherMock::new(Some(true)));
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(());
 
-        method.add_expected_call(ArgumentsMatcherMock::new(Some(true)));
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(());
 
         match method.call(ArgumentsMock) {
             Err(CallError::MoreThanOneMatching(_, _, method_calls)) => {
@@ -263,9 +1092,43 @@ mod test {
         }
     }
 
+    #[test]
+    fn most_recently_defined_call_is_used_when_more_than_one_call_matches() {
+        let mut method = Method::<_, u32>::new("test", debug_fn);
+        method.set_ambiguity_resolution(AmbiguityResolution::MostRecentlyDefined);
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .times(0)
+            .returns(1);
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(2);
+
+        assert_eq!(2, method.call(ArgumentsMock).unwrap());
+    }
+
+    #[test]
+    fn first_unsaturated_call_is_used_when_more_than_one_call_matches() {
+        let mut method = Method::<_, u32>::new("test", debug_fn);
+        method.set_ambiguity_resolution(AmbiguityResolution::FirstUnsaturated);
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(1);
+
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(2);
+
+        assert_eq!(1, method.call(ArgumentsMock).unwrap());
+        assert_eq!(2, method.call(ArgumentsMock).unwrap());
+    }
+
     #[test]
     fn call_errors_if_no_calls_match() {
-        let mut method = Method::<_, ()>::new("test");
+        let mut method = Method::<_, ()>::new("test", debug_fn);
 
         method
             .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
@@ -279,7 +1142,7 @@ mod test {
 
     #[test]
     fn call_calls_matching_method_call() {
-        let mut method = Method::<_, String>::new("test");
+        let mut method = Method::<_, String>::new("test", debug_fn);
 
         method
             .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
@@ -288,9 +1151,27 @@ mod test {
         assert_eq!(String::from("bar"), method.call(ArgumentsMock).unwrap());
     }
 
+    #[test]
+    fn call_uses_default_return_value_if_none_is_specified() {
+        let mut method = Method::<_, String>::new("test", debug_fn)
+            .with_default_return_value(Some(|| String::from("default")));
+
+        method.add_expected_call(ArgumentsMatcherMock::new(Some(true)));
+
+        assert_eq!(String::from("default"), method.call(ArgumentsMock).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "The expected call mock of test has no return value")]
+    fn add_expected_call_panics_if_no_return_value_is_specified() {
+        let mut method = Method::<_, String>::new("test", debug_fn);
+
+        method.add_expected_call(ArgumentsMatcherMock::new(None));
+    }
+
     #[test]
     fn errors_when_matching_call_is_called_more_than_expected() {
-        let mut method = Method::<_, ()>::new("test");
+        let mut method = Method::<_, ()>::new("test", debug_fn);
         method
             .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
             .times(1)
@@ -302,7 +1183,7 @@ mod test {
 
     #[test]
     fn verify_is_ok_if_expectations_are_met() {
-        let mut method = Method::<_, String>::new("test");
+        let mut method = Method::<_, String>::new("test", debug_fn);
 
         method
             .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
@@ -316,7 +1197,7 @@ mod test {
 
     #[test]
     fn verify_errors_if_expectations_not_met() {
-        let mut method = Method::<_, String>::new("test");
+        let mut method = Method::<_, String>::new("test", debug_fn);
 
         method
             .add_expected_call(ArgumentsMatcherMock::new(None))
@@ -328,14 +1209,14 @@ mod test {
 
     #[test]
     fn verify_is_ok_if_expectations_are_empty() {
-        let method = Method::<ArgumentsMatcherMock, String>::new("test");
+        let method = Method::<ArgumentsMatcherMock, String>::new("test", debug_fn);
 
         assert!(method.verify().is_ok());
     }
 
     #[test]
     fn unordered_expectations_work_with_one_matching_expected_call() {
-        let mut method = Method::<_, ()>::new("test");
+        let mut method = Method::<_, ()>::new("test", debug_fn);
 
         method
             .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
@@ -351,11 +1232,17 @@ mod test {
 
     #[test]
     fn unordered_expectations_fail_with_multiple_matching_calls() {
-        let mut method = Method::<_, ()>::new("test");
+        let mut method = Method::<_, ()>::new("test", debug_fn);
 
-        method.add_expected_call(ArgumentsMatcherMock::new(Some(true)));
-        method.add_expected_call(ArgumentsMatcherMock::new(Some(false)));
-        method.add_expected_call(ArgumentsMatcherMock::new(Some(true)));
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(());
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
+            .returns(());
+        method
+            .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
+            .returns(());
 
         let result = method.call(ArgumentsMock {});
 
@@ -364,7 +1251,7 @@ mod test {
 
     #[test]
     fn ordered_expectations_fail_if_first_call_does_not_match() {
-        let mut method = Method::<_, ()>::new("test");
+        let mut method = Method::<_, ()>::new("test", debug_fn);
 
         method
             .add_expected_call(ArgumentsMatcherMock::new(Some(false)))
@@ -381,7 +1268,7 @@ mod test {
 
     #[test]
     fn ordered_expectations_use_first_matching_call_regardless_of_other_expected_calls() {
-        let mut method = Method::<_, ()>::new("test");
+        let mut method = Method::<_, ()>::new("test", debug_fn);
 
         method
             .add_expected_call(ArgumentsMatcherMock::new(Some(true)))
@@ -395,5 +1282,4 @@ mod test {
 
         assert!(result.is_ok())
     }
-
 }
@@ -1,19 +1,32 @@
-use crate::internal::default_return_value::DefaultReturnValue;
+use crate::internal::expectation::{ExpectationHandle, ExpectationState, RecordReturnOnDrop};
 use crate::internal::expected_calls::ExpectedCalls;
-use crate::internal::fmt::{DisplayOption, DisplayTimes};
-use crate::internal::matcher::ArgumentsMatcher;
-use crate::internal::return_value::{self, ReturnValueGenerator};
+use crate::internal::fmt::{DebugFn, DisplayOption};
+use crate::internal::grammar::Grammar;
+use crate::internal::location::{caller_location, DisplayDefinedAt};
+use crate::internal::matcher::{ArgumentMismatch, ArgumentsMatcher};
+use crate::internal::return_value::{self, ReturnValue, ReturnValueGenerator};
+use crate::internal::sequence::{Sequence, SequenceError};
+use crate::internal::states::{StateGuard, StateTransition};
+use crate::internal::verification_report::ExpectedCallReport;
 use nameof::name_of;
-use std::cell::RefCell;
 use std::fmt::{self, Debug, Display};
-use std::rc::Rc;
+use std::marker::PhantomData;
+use std::panic::Location;
+use std::sync::Arc;
+use std::thread::panicking;
 
 /// Configures an expected method call.
 /// This builder is returned from the `expect_*` methods on a generated mock.
+///
+/// The builder panics when it is dropped without a return value being specified,
+/// unless the method returns `()`, the mock uses `#[mockable(default_returns)]`
+/// and the return type implements [`Default`], or the expected call is never called,
+/// i.e. `times(0)`.
 pub struct MethodCallBuilder<'mock, 'a, A, R>
 where
     A: for<'args> ArgumentsMatcher<'args>,
 {
+    name: &'static str,
     call: &'a mut MethodCall<'mock, A, R>,
 }
 
@@ -23,6 +36,7 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct(name_of!(type MethodCallBuilder<'mock, 'a, A, R>))
+            .field(name_of!(name in Self), &self.name)
             .field(name_of!(call in Self), &self.call)
             .finish()
     }
@@ -35,24 +49,43 @@ where
     /// Defines the return value for this method.
     /// The value must be [`Clone`]able.
     ///
-    /// This method does not need to be called on nightly for the unit type `()`.
+    /// This method does not need to be called for methods returning the unit type `()`.
     pub fn returns(&mut self, return_value: R) -> &mut Self
     where
         R: Clone + 'mock,
     {
-        self.call.return_value = Some(Rc::new(return_value::Cloned(return_value)));
+        self.call.return_value = Some(Arc::new(ReturnValue::Cloned(return_value::Cloned(
+            return_value,
+            R::clone,
+            self.call.debug_return_value,
+        ))));
         self
     }
 
+    /// Defines the return value for this async method.
+    /// The returned future is pending for the given number of polls before it resolves to the value.
+    /// This can be used to simulate futures that are not immediately ready.
+    ///
+    /// The number of polls is ignored for methods that are not async.
+    pub fn returns_after_polls(&mut self, polls: u32, return_value: R) -> &mut Self
+    where
+        R: Clone + 'mock,
+    {
+        self.call.pending_polls = polls;
+        self.returns(return_value)
+    }
+
     /// Defines that this method panics.
     pub fn panics(&mut self) -> &mut Self {
-        self.call.return_value = Some(Rc::new(return_value::Panic(None)));
+        self.call.return_value = Some(Arc::new(ReturnValue::Panic(return_value::Panic(None))));
         self
     }
 
     /// Defines that this method panics with a message.
     pub fn panics_with_message(&mut self, message: &'static str) -> &mut Self {
-        self.call.return_value = Some(Rc::new(return_value::Panic(Some(message))));
+        self.call.return_value = Some(Arc::new(ReturnValue::Panic(return_value::Panic(Some(
+            message,
+        )))));
         self
     }
 
@@ -69,6 +102,10 @@ where
     /// | Between (exclusive)   | [`Range`]            | `3..4`  |
     /// | Between (inclusive)   | [`RangeInclusive`]   | `3..=4` |
     ///
+    /// # Panics
+    /// Panics if this expected call is part of a [`Grammar`],
+    /// because the grammar defines how often it can be called.
+    ///
     /// [`u64`]: u64
     /// [`RangeFull`]: std::ops::RangeFull
     /// [`RangeFrom`]: std::ops::RangeFrom
@@ -76,24 +113,148 @@ where
     /// [`RangeToInclusive`]: std::ops::RangeToInclusive
     /// [`Range`]: std::ops::Range
     /// [`RangeInclusive`]: std::ops::RangeInclusive
+    /// [`Grammar`]: crate::Grammar
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
     pub fn times<E>(&mut self, expected_calls: E) -> &mut Self
     where
         E: Into<ExpectedCalls>,
     {
-        self.call.expected_calls = expected_calls.into();
+        if self.call.state.is_in_grammar() {
+            panic!(
+                "\n\nThe number of calls of the expected call {} cannot be set using `times(..)`, \
+                 because it is part of a grammar.\nThe number of calls of expected calls in a \
+                 grammar is defined by the grammar.\n",
+                self.handle()
+            );
+        }
+
+        self.call.state.set_expected_calls(expected_calls.into());
+        self
+    }
+
+    /// Adds this expected call to the end of the given [`Sequence`].
+    /// The calls in a sequence must be made in the order in which they were added.
+    ///
+    /// An expected call can only be part of one sequence.
+    /// Calling this method again removes the expected call from the previous sequence
+    /// and adds it to the end of the given sequence.
+    pub fn in_sequence(&mut self, sequence: &Sequence) -> &mut Self {
+        if let Some((previous_sequence, position)) = self.call.sequence.take() {
+            previous_sequence.remove(position);
+        }
+
+        let position = sequence.add(self.handle());
+        self.call.sequence = Some((sequence.clone(), position));
+        self
+    }
+
+    /// Defines that this method can only be called
+    /// once the given expected call has been called the expected number of times.
+    /// This can be used multiple times to depend on more than one expected call.
+    ///
+    /// # Examples
+    /// ```
+    /// use mockiato::mockable;
+    ///
+    /// # const IGNORED: &str = "
+    /// #[cfg_attr(test, mockable)]
+    /// # ";
+    /// # #[mockable]
+    /// trait Transaction {
+    ///     fn write(&self, data: &str);
+    ///     fn commit(&self);
+    /// }
+    ///
+    /// let mut transaction = TransactionMock::new();
+    /// let first_write = transaction
+    ///     .expect_write(|arg| arg.partial_eq("foo"))
+    ///     .handle();
+    /// let second_write = transaction
+    ///     .expect_write(|arg| arg.partial_eq("bar"))
+    ///     .handle();
+    /// transaction
+    ///     .expect_commit()
+    ///     .after(&first_write)
+    ///     .after(&second_write);
+    ///
+    /// transaction.write("bar");
+    /// transaction.write("foo");
+    /// transaction.commit();
+    /// ```
+    pub fn after(&mut self, expectation: &ExpectationHandle) -> &mut Self {
+        self.call.prerequisites.push(expectation.clone());
+        self
+    }
+
+    /// Defines that this method is only expected while the guard's [`States`] machine
+    /// is in the guarded state. Guards are created using [`States::is`].
+    ///
+    /// Other expected calls with the same arguments may be defined for other states.
+    ///
+    /// [`States`]: crate::States
+    /// [`States::is`]: crate::States::is
+    pub fn when(&mut self, guard: StateGuard) -> &mut Self {
+        self.call.guard = Some(guard);
         self
     }
 
-    pub(crate) fn new(call: &'a mut MethodCall<'mock, A, R>) -> Self {
-        Self { call }
+    /// Defines that calling this method changes the state of a [`States`] machine.
+    /// Transitions are created using [`States::to`].
+    ///
+    /// [`States`]: crate::States
+    /// [`States::to`]: crate::States::to
+    pub fn then_state(&mut self, transition: StateTransition) -> &mut Self {
+        self.call.transition = Some(transition);
+        self
+    }
+
+    /// Returns a handle to this expected call.
+    /// The handle can be used to define that other expected calls
+    /// must happen after this one using [`MethodCallBuilder::after`]
+    /// and to inspect how often this expected call was called.
+    pub fn handle(&self) -> ExpectationHandle {
+        ExpectationHandle::new(
+            self.call.matcher.to_string(),
+            self.call.location,
+            self.call.state.clone(),
+        )
+    }
+
+    pub(crate) fn new(name: &'static str, call: &'a mut MethodCall<'mock, A, R>) -> Self {
+        Self { name, call }
+    }
+}
+
+impl<'mock, 'a, A, R> Drop for MethodCallBuilder<'mock, 'a, A, R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn drop(&mut self) {
+        if !panicking() && self.call.return_value.is_none() && self.call.can_be_called() {
+            panic!(
+                "\n\nThe expected call {} of {} has no return value{}.\nUse `returns(..)`, \
+                 `panics()` or `panics_with_message(..)` to specify one.\n",
+                self.call.matcher,
+                self.name,
+                DisplayDefinedAt(self.call.location)
+            );
+        }
     }
 }
 
 pub struct MethodCall<'mock, A, R> {
-    expected_calls: ExpectedCalls,
-    actual_number_of_calls: RefCell<u64>,
-    matcher: Rc<A>,
-    return_value: Option<Rc<dyn ReturnValueGenerator<A, R> + 'mock>>,
+    state: Arc<ExpectationState>,
+    sequence: Option<(Sequence, usize)>,
+    prerequisites: Vec<ExpectationHandle>,
+    guard: Option<StateGuard>,
+    transition: Option<StateTransition>,
+    matcher: Arc<A>,
+    return_value: Option<Arc<ReturnValue<R>>>,
+    /// The number of polls for which the future returned by an async method is pending.
+    pending_polls: u32,
+    debug_return_value: DebugFn<R>,
+    location: Option<&'static Location<'static>>,
+    phantom_data: PhantomData<&'mock ()>,
 }
 
 impl<'mock, A, R> Debug for MethodCall<'mock, A, R>
@@ -102,13 +263,15 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct(name_of!(type MethodCall<'mock, A, R>))
-            .field(name_of!(expected_calls in Self), &self.expected_calls)
-            .field(
-                name_of!(actual_number_of_calls in Self),
-                &self.actual_number_of_calls,
-            )
+            .field(name_of!(state in Self), &self.state)
+            .field(name_of!(sequence in Self), &self.sequence)
+            .field(name_of!(prerequisites in Self), &self.prerequisites)
+            .field(name_of!(guard in Self), &self.guard)
+            .field(name_of!(transition in Self), &self.transition)
             .field(name_of!(matcher in Self), &self.matcher)
             .field(name_of!(return_value in Self), &self.return_value)
+            .field(name_of!(pending_polls in Self), &self.pending_polls)
+            .field(name_of!(location in Self), &self.location)
             .finish()
     }
 }
@@ -119,10 +282,17 @@ where
 {
     fn clone(&self) -> Self {
         Self {
-            expected_calls: self.expected_calls.clone(),
-            actual_number_of_calls: self.actual_number_of_calls.clone(),
+            state: Arc::new(ExpectationState::clone(&self.state)),
+            sequence: self.sequence.clone(),
+            prerequisites: self.prerequisites.clone(),
+            guard: self.guard.clone(),
+            transition: self.transition.clone(),
             matcher: self.matcher.clone(),
             return_value: self.return_value.clone(),
+            pending_polls: self.pending_polls,
+            debug_return_value: self.debug_return_value,
+            location: self.location,
+            phantom_data: PhantomData,
         }
     }
 }
@@ -131,34 +301,140 @@ impl<'mock, A, R> MethodCall<'mock, A, R>
 where
     A: for<'args> ArgumentsMatcher<'args>,
 {
-    pub(crate) fn new(matcher: A) -> Self {
+    /// Creates a new expected call.
+    /// The location of the caller is recorded to display where the call was defined.
+    #[cfg_attr(rustc_has_track_caller, track_caller)]
+    pub(crate) fn new(
+        matcher: A,
+        debug_return_value: DebugFn<R>,
+        default_return_value: Option<Arc<ReturnValue<R>>>,
+    ) -> Self {
         Self {
-            expected_calls: ExpectedCalls::default(),
-            actual_number_of_calls: RefCell::default(),
-            matcher: Rc::new(matcher),
-            return_value: R::default_return_value(),
+            state: Arc::default(),
+            sequence: None,
+            prerequisites: Vec::new(),
+            guard: None,
+            transition: None,
+            matcher: Arc::new(matcher),
+            return_value: default_return_value,
+            pending_polls: 0,
+            debug_return_value,
+            location: caller_location(),
+            phantom_data: PhantomData,
         }
     }
 
-    pub(crate) fn call(&self, arguments: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
-        *self.actual_number_of_calls.borrow_mut() += 1;
+    /// Counts a call to this expected call and applies its state transition.
+    pub(crate) fn accept(&self) {
+        self.state.record_call();
+
+        if let Some(ref transition) = self.transition {
+            transition.apply();
+        }
+    }
+
+    /// Produces the return value of a call that was accepted using [`MethodCall::accept`].
+    /// Panics with the name of the method and the location of the definition
+    /// if no return value was specified.
+    pub(crate) fn call(
+        &self,
+        name: &'static str,
+        arguments: <A as ArgumentsMatcher<'_>>::Arguments,
+    ) -> R {
+        let _record_return = RecordReturnOnDrop(&self.state);
 
         match self.return_value {
-            Some(ref return_value) => return_value.generate_return_value(arguments),
-            None => panic!("No return value was specified"),
+            Some(ref return_value) => {
+                ReturnValueGenerator::<A, R>::generate_return_value(&**return_value, arguments)
+            }
+            None => panic!(
+                "\n\nThe expected call {} of {} has no return value{}.\nUse `returns(..)`, \
+                 `panics()` or `panics_with_message(..)` to specify one.\n",
+                self.matcher,
+                name,
+                DisplayDefinedAt(self.location)
+            ),
         }
     }
 
+    pub(crate) fn report(&self) -> ExpectedCallReport {
+        ExpectedCallReport::new(
+            self.description(),
+            self.state.expected_calls(),
+            self.state.actual_number_of_calls(),
+            self.location,
+        )
+    }
+
+    /// Describes the expected arguments and the behaviour of this call
+    /// without how often it is expected to be called.
+    fn description(&self) -> String {
+        let mut description = self.matcher.to_string();
+
+        if let Some(ref guard) = self.guard {
+            description += &format!(" {}", guard);
+        }
+
+        description += &format!(" -> {}", DisplayOption(self.return_value.as_ref()));
+
+        match self.pending_polls {
+            0 => {}
+            1 => description += " after 1 poll",
+            pending_polls => description += &format!(" after {} polls", pending_polls),
+        }
+
+        if let Some(ref transition) = self.transition {
+            description += &format!(" {}", transition);
+        }
+
+        description
+    }
+
+    pub(crate) fn pending_polls(&self) -> u32 {
+        self.pending_polls
+    }
+
     pub(crate) fn was_called_expected_number_of_times(&self) -> bool {
-        self.expected_calls
-            .contains(*self.actual_number_of_calls.borrow())
+        self.state.was_called_expected_number_of_times()
+    }
+
+    /// Returns `false` if the expected call must never be called, e.g. using `times(0)`.
+    fn can_be_called(&self) -> bool {
+        self.state.expected_calls().max_value() != Some(0)
     }
 
     pub(crate) fn accepts_more_calls(&self) -> bool {
-        let number_of_calls = *self.actual_number_of_calls.borrow();
-        match self.expected_calls.max_value() {
-            Some(max_value) => number_of_calls < max_value,
-            None => true,
+        self.state.accepts_more_calls()
+    }
+
+    /// Returns `true` if this call has no state guard or its guard is satisfied.
+    pub(crate) fn is_in_expected_state(&self) -> bool {
+        self.guard.as_ref().map_or(true, StateGuard::is_satisfied)
+    }
+
+    pub(crate) fn guard(&self) -> Option<&StateGuard> {
+        self.guard.as_ref()
+    }
+
+    /// Returns the first expected call this call depends on
+    /// that has not been called the expected number of times yet.
+    pub(crate) fn unsatisfied_prerequisite(&self) -> Option<&ExpectationHandle> {
+        self.prerequisites
+            .iter()
+            .find(|prerequisite| !prerequisite.state().was_called_expected_number_of_times())
+    }
+
+    /// Returns the [`Grammar`]s this call is part of, together with its symbol in each grammar.
+    pub(crate) fn grammars(&self) -> Vec<(Grammar, usize)> {
+        self.state.grammars()
+    }
+
+    /// Advances the [`Sequence`] of this call to this call
+    /// if the call may be made now with respect to the sequence.
+    pub(crate) fn try_advance_sequence(&self) -> Result<(), SequenceError> {
+        match self.sequence {
+            Some((ref sequence, position)) => sequence.try_advance_to(position),
+            None => Ok(()),
         }
     }
 
@@ -168,6 +444,21 @@ where
     ) -> bool {
         self.matcher.matches_arguments(arguments)
     }
+
+    pub(crate) fn argument_mismatches<'a>(
+        &self,
+        arguments: &<A as ArgumentsMatcher<'a>>::Arguments,
+    ) -> Vec<ArgumentMismatch> {
+        self.matcher.argument_mismatches(arguments)
+    }
+
+    pub(crate) fn specificity(&self) -> usize {
+        self.matcher.specificity()
+    }
+
+    pub(crate) fn matcher(&self) -> &A {
+        &self.matcher
+    }
 }
 
 impl<'mock, A, R> Display for MethodCall<'mock, A, R>
@@ -175,14 +466,7 @@ where
     A: for<'args> ArgumentsMatcher<'args>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{} -> {} {}, was called {}",
-            self.matcher,
-            DisplayOption(self.return_value.as_ref()),
-            self.expected_calls,
-            DisplayTimes(*self.actual_number_of_calls.borrow())
-        )
+        write!(f, "{}", self.report())
     }
 }
 
@@ -191,100 +475,83 @@ mod test {
     use super::*;
     use crate::internal::arguments::ArgumentsMock;
     use crate::internal::matcher::ArgumentsMatcherMock;
-    use std::cell::RefCell;
-    use std::fmt::Debug;
-    use std::thread::panicking;
 
-    #[derive(Debug)]
-    struct ReturnValueGeneratorMock<R>
-    where
-        R: Clone + Debug,
-    {
-        generate_return_value_was_called: RefCell<bool>,
-        return_value: Option<R>,
+    fn debug_fn<T>(_: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mock")
     }
 
-    impl<R> ReturnValueGeneratorMock<R>
+    fn cloned<R>(value: R) -> Option<Arc<ReturnValue<R>>>
     where
-        R: Clone + Debug,
+        R: Clone,
     {
-        fn new(return_value: Option<R>) -> Self {
-            Self {
-                return_value,
-                generate_return_value_was_called: RefCell::default(),
-            }
-        }
+        Some(Arc::new(ReturnValue::Cloned(return_value::Cloned(
+            value,
+            R::clone,
+            debug_fn,
+        ))))
     }
 
-    impl<R> Display for ReturnValueGeneratorMock<R>
-    where
-        R: Clone + Debug,
-    {
-        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
-            panic!();
-        }
+    #[test]
+    #[should_panic(expected = "The expected call mock of test has no return value")]
+    fn builder_panics_when_dropped_without_return_value() {
+        let mut call: MethodCall<'_, _, String> =
+            MethodCall::new(ArgumentsMatcherMock::new(None), debug_fn, None);
+
+        MethodCallBuilder::new("test", &mut call);
     }
 
-    impl<A, R> ReturnValueGenerator<A, R> for ReturnValueGeneratorMock<R>
-    where
-        A: for<'args> ArgumentsMatcher<'args>,
-        R: Clone + Debug,
-    {
-        fn generate_return_value(&self, _input: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
-            *self.generate_return_value_was_called.borrow_mut() = true;
+    #[test]
+    fn builder_does_not_panic_when_dropped_with_return_value() {
+        let mut call: MethodCall<'_, _, String> =
+            MethodCall::new(ArgumentsMatcherMock::new(None), debug_fn, None);
 
-            self.return_value
-                .as_ref()
-                .expect("Return value was not specified for mock")
-                .clone()
-        }
+        MethodCallBuilder::new("test", &mut call).returns(String::from("foo"));
     }
 
-    impl<R> Drop for ReturnValueGeneratorMock<R>
-    where
-        R: Clone + Debug,
-    {
-        fn drop(&mut self) {
-            if !panicking() && self.return_value.is_some() {
-                assert!(
-                    *self.generate_return_value_was_called.borrow(),
-                    "generate_return_value_was_called() was never called"
-                );
-            }
-        }
+    #[test]
+    fn builder_does_not_panic_when_dropped_with_default_return_value() {
+        let mut call: MethodCall<'_, _, String> = MethodCall::new(
+            ArgumentsMatcherMock::new(None),
+            debug_fn,
+            cloned(String::new()),
+        );
+
+        MethodCallBuilder::new("test", &mut call);
     }
 
     #[test]
-    #[should_panic(expected = "No return value was specified")]
-    fn call_panics_if_no_return_value_is_specified() {
-        let call: MethodCall<'_, _, String> = MethodCall::new(ArgumentsMatcherMock::new(None));
+    fn builder_does_not_panic_when_dropped_without_return_value_if_never_called() {
+        let mut call: MethodCall<'_, _, String> =
+            MethodCall::new(ArgumentsMatcherMock::new(None), debug_fn, None);
 
-        call.call(ArgumentsMock);
+        MethodCallBuilder::new("test", &mut call).times(0);
+        MethodCallBuilder::new("test", &mut call).times(..=0);
+        MethodCallBuilder::new("test", &mut call).times(0..1);
     }
 
     #[test]
     fn call_uses_return_value() {
-        let mut call: MethodCall<'_, _, String> = MethodCall::new(ArgumentsMatcherMock::new(None));
+        let mut call: MethodCall<'_, _, String> =
+            MethodCall::new(ArgumentsMatcherMock::new(None), debug_fn, None);
 
-        call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(Some(String::from(
-            "foo",
-        )))));
+        call.return_value = cloned(String::from("foo"));
 
-        let return_value = call.call(ArgumentsMock);
+        let return_value = call.call("test", ArgumentsMock);
 
         assert_eq!(String::from("foo"), return_value);
     }
 
     #[test]
     fn was_called_expected_number_of_times_returns_true() {
-        let mut call: MethodCall<'_, _, ()> = MethodCall::new(ArgumentsMatcherMock::new(None));
-        call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(Some(()))));
-        call.expected_calls = 4.into();
+        let mut call: MethodCall<'_, _, ()> =
+            MethodCall::new(ArgumentsMatcherMock::new(None), debug_fn, None);
+        call.return_value = cloned(());
+        call.state.set_expected_calls(4.into());
 
-        call.call(ArgumentsMock);
-        call.call(ArgumentsMock);
-        call.call(ArgumentsMock);
-        call.call(ArgumentsMock);
+        call.accept();
+        call.accept();
+        call.accept();
+        call.accept();
 
         assert!(call.was_called_expected_number_of_times());
     }
@@ -292,13 +559,13 @@ mod test {
     #[test]
     fn was_called_expected_number_of_times_returns_false() {
         let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(None));
-            call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(Some(()))));
-            call.expected_calls = (2..).into();
+            let mut call = MethodCall::new(ArgumentsMatcherMock::new(None), debug_fn, None);
+            call.return_value = cloned(());
+            call.state.set_expected_calls((2..).into());
             call
         };
 
-        call.call(ArgumentsMock);
+        call.accept();
 
         assert!(!call.was_called_expected_number_of_times());
     }
@@ -306,8 +573,8 @@ mod test {
     #[test]
     fn matches_expected_arguments_returns_true() {
         let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(Some(true)));
-            call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(None)));
+            let mut call = MethodCall::new(ArgumentsMatcherMock::new(Some(true)), debug_fn, None);
+            call.return_value = Some(Arc::new(ReturnValue::Panic(return_value::Panic(None))));
             call
         };
 
@@ -317,8 +584,8 @@ mod test {
     #[test]
     fn matches_expected_arguments_returns_false() {
         let call: MethodCall<'_, _, ()> = {
-            let mut call = MethodCall::new(ArgumentsMatcherMock::new(Some(false)));
-            call.return_value = Some(Rc::new(ReturnValueGeneratorMock::new(None)));
+            let mut call = MethodCall::new(ArgumentsMatcherMock::new(Some(false)), debug_fn, None);
+            call.return_value = Some(Arc::new(ReturnValue::Panic(return_value::Panic(None))));
             call
         };
 
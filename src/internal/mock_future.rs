@@ -0,0 +1,97 @@
+use nameof::name_of;
+use std::fmt::{self, Debug};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// The future returned by the async methods of a generated mock.
+///
+/// The future is pending for the number of polls configured using
+/// [`MethodCallBuilder::returns_after_polls`] before it resolves to the return value.
+///
+/// [`MethodCallBuilder::returns_after_polls`]: crate::MethodCallBuilder::returns_after_polls
+pub struct MockFuture<R> {
+    pending_polls: u32,
+    return_value: Option<R>,
+}
+
+impl<R> MockFuture<R> {
+    pub(crate) fn new(pending_polls: u32, return_value: R) -> Self {
+        Self {
+            pending_polls,
+            return_value: Some(return_value),
+        }
+    }
+
+    pub(crate) fn ready(return_value: R) -> Self {
+        Self::new(0, return_value)
+    }
+}
+
+// The return value is never pinned.
+impl<R> Unpin for MockFuture<R> {}
+
+impl<R> Future for MockFuture<R> {
+    type Output = R;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.pending_polls > 0 {
+            self.pending_polls -= 1;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        Poll::Ready(
+            self.return_value
+                .take()
+                .expect("MockFuture was polled after it completed"),
+        )
+    }
+}
+
+impl<R> Debug for MockFuture<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(name_of!(type MockFuture<R>))
+            .field(name_of!(pending_polls in Self), &self.pending_polls)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::ptr;
+    use std::task::{RawWaker, RawWakerVTable, Waker};
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(ptr::null(), &VTABLE)
+        }
+
+        fn noop(_: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+    }
+
+    #[test]
+    fn is_pending_for_configured_number_of_polls() {
+        let waker = noop_waker();
+        let mut context = Context::from_waker(&waker);
+        let mut future = MockFuture::new(2, 42);
+
+        assert_eq!(Poll::Pending, Pin::new(&mut future).poll(&mut context));
+        assert_eq!(Poll::Pending, Pin::new(&mut future).poll(&mut context));
+        assert_eq!(Poll::Ready(42), Pin::new(&mut future).poll(&mut context));
+    }
+
+    #[test]
+    fn ready_future_resolves_on_first_poll() {
+        let waker = noop_waker();
+        let mut context = Context::from_waker(&waker);
+        let mut future = MockFuture::ready("foo");
+
+        assert_eq!(Poll::Ready("foo"), Pin::new(&mut future).poll(&mut context));
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt::{self, Display};
+
+/// A call that was made to a mocked method.
+/// Recorded calls are returned by the `calls_to_<method_name>` and `recorded_calls`
+/// methods of a generated mock.
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+/// greeter
+///     .allow_greet(|arg| arg.any())
+///     .returns(String::from("Hello"));
+///
+/// greeter.greet("Jane");
+/// greeter.greet("Paul");
+///
+/// let calls = greeter.calls_to_greet();
+/// assert_eq!(2, calls.len());
+/// assert_eq!(["\"Paul\""], calls[1].arguments());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedCall {
+    method_name: &'static str,
+    arguments: Vec<String>,
+    matched_expectation: Option<String>,
+    sequence_number: usize,
+}
+
+impl RecordedCall {
+    pub(crate) fn new(
+        method_name: &'static str,
+        arguments: Vec<String>,
+        matched_expectation: Option<String>,
+        sequence_number: usize,
+    ) -> Self {
+        Self {
+            method_name,
+            arguments,
+            matched_expectation,
+            sequence_number,
+        }
+    }
+
+    /// Returns the name of the method, including the module path of the mock.
+    pub fn method_name(&self) -> &str {
+        self.method_name
+    }
+
+    /// Returns the arguments of the call, formatted using their [`Debug`] implementation.
+    /// Arguments that don't implement [`Debug`] are displayed as `?`.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+
+    /// Returns the expected or allowed call that was used for this call.
+    /// Returns [`None`] if no expected call matched, which can only happen for nice mocks.
+    pub fn matched_expectation(&self) -> Option<&str> {
+        self.matched_expectation.as_ref().map(String::as_str)
+    }
+
+    /// Returns a number that increases with each call to any mock.
+    /// This can be used to compare the order of calls across methods and mocks.
+    pub fn sequence_number(&self) -> usize {
+        self.sequence_number
+    }
+}
+
+impl Display for RecordedCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.method_name, self.arguments.join(", "))
+    }
+}
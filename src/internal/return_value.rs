@@ -1,34 +1,66 @@
 pub(crate) use self::cloned::*;
+pub(crate) use self::default_value::*;
 pub(crate) use self::panic::*;
 
 use crate::internal::ArgumentsMatcher;
-use std::fmt::{Debug, Display};
-use std::rc::Rc;
+use std::fmt::{self, Debug, Display};
 
 mod cloned;
+mod default_value;
 mod panic;
 
-pub trait DefaultReturnValue<A>: Sized {
-    fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, Self>>> {
-        None
-    }
+pub trait ReturnValueGenerator<A, R>: Display + Debug
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn generate_return_value(&self, input: <A as ArgumentsMatcher<'_>>::Arguments) -> R;
 }
 
-impl<A, T> DefaultReturnValue<A> for T where A: for<'args> ArgumentsMatcher<'args> {}
+/// The return value of an expected call.
+///
+/// The generators are stored in an enum instead of a trait object,
+/// so that expected calls are [`Send`] and [`Sync`] if the return value is.
+pub(crate) enum ReturnValue<R> {
+    Cloned(Cloned<R>),
+    DefaultValue(DefaultValue<R>),
+    Panic(Panic),
+}
 
-#[cfg(rustc_is_nightly)]
-impl<A> DefaultReturnValue<A> for ()
+impl<A, R> ReturnValueGenerator<A, R> for ReturnValue<R>
 where
     A: for<'args> ArgumentsMatcher<'args>,
 {
-    fn default_return_value() -> Option<Rc<dyn ReturnValueGenerator<A, ()>>> {
-        Some(Rc::new(Cloned(())))
+    fn generate_return_value(&self, input: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        match self {
+            ReturnValue::Cloned(cloned) => {
+                ReturnValueGenerator::<A, R>::generate_return_value(cloned, input)
+            }
+            ReturnValue::DefaultValue(default_value) => {
+                ReturnValueGenerator::<A, R>::generate_return_value(default_value, input)
+            }
+            ReturnValue::Panic(panic) => {
+                ReturnValueGenerator::<A, R>::generate_return_value(panic, input)
+            }
+        }
     }
 }
 
-pub trait ReturnValueGenerator<A, R>: Display + Debug
-where
-    A: for<'args> ArgumentsMatcher<'args>,
-{
-    fn generate_return_value(&self, input: <A as ArgumentsMatcher<'_>>::Arguments) -> R;
+impl<R> Display for ReturnValue<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReturnValue::Cloned(cloned) => Display::fmt(cloned, f),
+            ReturnValue::DefaultValue(default_value) => Display::fmt(default_value, f),
+            ReturnValue::Panic(panic) => Display::fmt(panic, f),
+        }
+    }
+}
+
+impl<R> Debug for ReturnValue<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReturnValue::Cloned(cloned) => Debug::fmt(cloned, f),
+            ReturnValue::DefaultValue(default_value) => Debug::fmt(default_value, f),
+            ReturnValue::Panic(panic) => Debug::fmt(panic, f),
+        }
+    }
 }
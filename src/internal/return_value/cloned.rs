@@ -1,35 +1,34 @@
 use super::ReturnValueGenerator;
-use crate::internal::fmt::MaybeDebug;
+use crate::internal::fmt::DebugFn;
 use crate::internal::ArgumentsMatcher;
 use std::fmt::{self, Debug, Display};
 
-pub struct Cloned<T>(pub(crate) T);
+/// Returns a clone of the given value.
+/// The value is cloned using the [`CloneFn`], so that cloning doesn't require a `Clone` bound.
+/// The [`DebugFn`] is used to display the value.
+pub struct Cloned<T>(pub(crate) T, pub(crate) CloneFn<T>, pub(crate) DebugFn<T>);
+
+/// A function that clones a value.
+pub(crate) type CloneFn<T> = fn(&T) -> T;
 
 impl<A, R> ReturnValueGenerator<A, R> for Cloned<R>
 where
-    R: Clone,
     A: for<'args> ArgumentsMatcher<'args>,
 {
     fn generate_return_value(&self, _: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
-        self.0.clone()
+        (self.1)(&self.0)
     }
 }
 
-impl<R> Display for Cloned<R>
-where
-    R: MaybeDebug,
-{
+impl<R> Display for Cloned<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        MaybeDebug::fmt(&self.0, f)
+        (self.2)(&self.0, f)
     }
 }
 
-impl<R> Debug for Cloned<R>
-where
-    R: MaybeDebug,
-{
+impl<R> Debug for Cloned<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        MaybeDebug::fmt(&self.0, f)
+        (self.2)(&self.0, f)
     }
 }
 
@@ -41,7 +40,11 @@ mod test {
 
     #[test]
     fn test_cloned_returns_expected_value() {
-        let cloned = Cloned(String::from("foo"));
+        let cloned = Cloned(
+            String::from("foo"),
+            <String as Clone>::clone,
+            <String as Debug>::fmt,
+        );
 
         assert_eq!(
             String::from("foo"),
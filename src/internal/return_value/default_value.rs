@@ -0,0 +1,57 @@
+use super::ReturnValueGenerator;
+use crate::internal::fmt::DebugFn;
+use crate::internal::ArgumentsMatcher;
+use std::fmt::{self, Debug, Display};
+
+/// Returns the value created by the given function.
+/// This is used for return values that don't need to be specified explicitly.
+/// The [`DebugFn`] is used to display the value.
+pub struct DefaultValue<T>(pub(crate) fn() -> T, pub(crate) DebugFn<T>);
+
+impl<A, R> ReturnValueGenerator<A, R> for DefaultValue<R>
+where
+    A: for<'args> ArgumentsMatcher<'args>,
+{
+    fn generate_return_value(&self, _: <A as ArgumentsMatcher<'_>>::Arguments) -> R {
+        (self.0)()
+    }
+}
+
+impl<R> Display for DefaultValue<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(&(self.0)(), f)
+    }
+}
+
+impl<R> Debug for DefaultValue<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(&(self.0)(), f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::internal::arguments::ArgumentsMock;
+    use crate::internal::matcher::ArgumentsMatcherMock;
+
+    #[test]
+    fn test_default_value_returns_generated_value() {
+        let default_value = DefaultValue(|| 42, <i32 as Debug>::fmt);
+
+        assert_eq!(
+            42,
+            ReturnValueGenerator::<ArgumentsMatcherMock, i32>::generate_return_value(
+                &default_value,
+                ArgumentsMock
+            )
+        );
+    }
+
+    #[test]
+    fn test_default_value_is_displayed_using_debug_fn() {
+        let default_value = DefaultValue(|| (), <() as Debug>::fmt);
+
+        assert_eq!("()", format!("{}", default_value));
+    }
+}
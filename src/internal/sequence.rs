@@ -0,0 +1,220 @@
+use crate::internal::expectation::ExpectationHandle;
+use crate::internal::lock::lock;
+use std::fmt::{self, Display};
+use std::sync::{Arc, Mutex};
+
+/// Defines an order for expected calls across methods and mocks.
+///
+/// Expected calls are added to a sequence using [`MethodCallBuilder::in_sequence`].
+/// The calls must then be made in the same order as the expected calls were added.
+/// An expected call can only be called when all expected calls added before it
+/// have been called the expected number of times.
+/// Once a call was made, the expected calls added before it can no longer be called.
+///
+/// A sequence can be shared between multiple mocks.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, Sequence};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Connection {
+///     fn open(&self);
+///     fn write(&self, data: &str);
+///     fn close(&self);
+/// }
+///
+/// let sequence = Sequence::new();
+/// let mut connection = ConnectionMock::new();
+///
+/// connection.expect_open().in_sequence(&sequence);
+/// connection
+///     .expect_write(|arg| arg.any())
+///     .times(3)
+///     .in_sequence(&sequence);
+/// connection.expect_close().in_sequence(&sequence);
+///
+/// connection.open();
+/// connection.write("foo");
+/// connection.write("bar");
+/// connection.write("baz");
+/// connection.close();
+/// ```
+///
+/// [`MethodCallBuilder::in_sequence`]: crate::MethodCallBuilder::in_sequence
+#[derive(Clone, Debug, Default)]
+pub struct Sequence(Arc<Mutex<SequenceState>>);
+
+#[derive(Debug, Default)]
+struct SequenceState {
+    /// Entries are [`None`] once they were removed,
+    /// so that the positions of the following entries stay the same.
+    entries: Vec<Option<ExpectationHandle>>,
+    position: usize,
+}
+
+impl Sequence {
+    /// Creates a new sequence with no expected calls.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an expected call to the end of this sequence
+    /// and returns its position.
+    pub(crate) fn add(&self, expectation: ExpectationHandle) -> usize {
+        let mut sequence = lock(&self.0);
+
+        sequence.entries.push(Some(expectation));
+
+        sequence.entries.len() - 1
+    }
+
+    /// Removes the expected call at the given position from this sequence.
+    pub(crate) fn remove(&self, position: usize) {
+        lock(&self.0).entries[position] = None;
+    }
+
+    /// Advances the sequence to the given position
+    /// if the expected call at this position may be called now.
+    /// The sequence is locked while checking and advancing, so that calls
+    /// on different threads can't both pass the check before either one advances.
+    pub(crate) fn try_advance_to(&self, position: usize) -> Result<(), SequenceError> {
+        let mut sequence = lock(&self.0);
+
+        if position < sequence.position {
+            let current_entry = sequence.entries[..=sequence.position]
+                .iter()
+                .rev()
+                .flatten()
+                .next()
+                .expect("The entry at the given position has not been removed");
+            return Err(SequenceError::new(current_entry));
+        }
+
+        let unsatisfied_entry = sequence.entries[sequence.position..position]
+            .iter()
+            .flatten()
+            .find(|entry| !entry.state().was_called_expected_number_of_times());
+
+        if let Some(entry) = unsatisfied_entry {
+            return Err(SequenceError::new(entry));
+        }
+
+        sequence.position = position;
+        Ok(())
+    }
+}
+
+/// The error returned when an expected call is called out of sequence.
+#[derive(Debug)]
+pub(crate) struct SequenceError {
+    expected_call: String,
+}
+
+impl SequenceError {
+    fn new(entry: &ExpectationHandle) -> Self {
+        Self {
+            expected_call: entry.to_string(),
+        }
+    }
+}
+
+impl Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expected_call)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::internal::expectation::ExpectationState;
+    use crate::internal::expected_calls::ExpectedCalls;
+
+    fn add_entry<E>(
+        sequence: &Sequence,
+        description: &str,
+        expected_calls: E,
+    ) -> (usize, Arc<ExpectationState>)
+    where
+        E: Into<ExpectedCalls>,
+    {
+        let state = Arc::new(ExpectationState::default());
+        state.set_expected_calls(expected_calls.into());
+        let position = sequence.add(ExpectationHandle::new(
+            description.to_string(),
+            None,
+            state.clone(),
+        ));
+        (position, state)
+    }
+
+    #[test]
+    fn calls_in_order_are_accepted() {
+        let sequence = Sequence::new();
+        let (first, first_state) = add_entry(&sequence, "first()", 1);
+        let (second, _) = add_entry(&sequence, "second()", 1);
+
+        assert!(sequence.try_advance_to(first).is_ok());
+        first_state.record_call();
+        assert!(sequence.try_advance_to(second).is_ok());
+    }
+
+    #[test]
+    fn call_is_rejected_if_previous_call_is_not_satisfied() {
+        let sequence = Sequence::new();
+        let (_, _) = add_entry(&sequence, "first()", 1);
+        let (second, _) = add_entry(&sequence, "second()", 1);
+
+        let error = sequence.try_advance_to(second).unwrap_err();
+
+        assert_eq!(
+            "first() exactly 1 time, was called 0 times",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn rejected_call_does_not_advance_sequence() {
+        let sequence = Sequence::new();
+        let (first, first_state) = add_entry(&sequence, "first()", 1);
+        let (second, _) = add_entry(&sequence, "second()", 1);
+
+        assert!(sequence.try_advance_to(second).is_err());
+
+        assert!(sequence.try_advance_to(first).is_ok());
+        first_state.record_call();
+        assert!(sequence.try_advance_to(second).is_ok());
+    }
+
+    #[test]
+    fn removed_call_is_skipped() {
+        let sequence = Sequence::new();
+        let (first, _) = add_entry(&sequence, "first()", 1);
+        let (second, _) = add_entry(&sequence, "second()", 1);
+
+        sequence.remove(first);
+
+        assert!(sequence.try_advance_to(second).is_ok());
+    }
+
+    #[test]
+    fn earlier_call_is_rejected_after_sequence_advanced() {
+        let sequence = Sequence::new();
+        let (first, first_state) = add_entry(&sequence, "first()", 1..);
+        let (second, _) = add_entry(&sequence, "second()", 1);
+
+        first_state.record_call();
+        assert!(sequence.try_advance_to(second).is_ok());
+
+        let error = sequence.try_advance_to(first).unwrap_err();
+
+        assert_eq!(
+            "second() exactly 1 time, was called 0 times",
+            error.to_string()
+        );
+    }
+}
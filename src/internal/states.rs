@@ -0,0 +1,191 @@
+use crate::internal::lock::lock;
+use std::fmt::{self, Display};
+use std::sync::{Arc, Mutex};
+
+/// A state machine that expected calls can depend on and transition.
+///
+/// Expected calls can be restricted to a state using [`MethodCallBuilder::when`]
+/// and change the state when they are called using [`MethodCallBuilder::then_state`].
+/// Calls that only match expected calls restricted to another state
+/// are reported together with the current state.
+///
+/// A state machine can be shared between multiple mocks.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, States};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Connection {
+///     fn open(&self);
+///     fn read(&self) -> Option<String>;
+///     fn close(&self);
+/// }
+///
+/// let connection_state = States::new("connection", "closed");
+/// let mut connection = ConnectionMock::new();
+///
+/// connection
+///     .expect_open()
+///     .when(connection_state.is("closed"))
+///     .then_state(connection_state.to("open"));
+/// connection
+///     .expect_read()
+///     .times(..)
+///     .when(connection_state.is("open"))
+///     .returns(Some(String::from("data")));
+/// connection
+///     .expect_read()
+///     .times(..)
+///     .when(connection_state.is("closed"))
+///     .returns(None);
+/// connection
+///     .expect_close()
+///     .when(connection_state.is("open"))
+///     .then_state(connection_state.to("closed"));
+///
+/// assert_eq!(None, connection.read());
+/// connection.open();
+/// assert_eq!(Some(String::from("data")), connection.read());
+/// connection.close();
+/// assert_eq!(None, connection.read());
+/// ```
+///
+/// [`MethodCallBuilder::when`]: crate::MethodCallBuilder::when
+/// [`MethodCallBuilder::then_state`]: crate::MethodCallBuilder::then_state
+#[derive(Clone, Debug)]
+pub struct States(Arc<StatesState>);
+
+#[derive(Debug)]
+struct StatesState {
+    name: String,
+    current: Mutex<String>,
+}
+
+impl States {
+    /// Creates a new state machine with the given name, starting in the given state.
+    /// The name is used in error messages.
+    pub fn new(name: &str, initial_state: &str) -> Self {
+        States(Arc::new(StatesState {
+            name: name.to_string(),
+            current: Mutex::new(initial_state.to_string()),
+        }))
+    }
+
+    /// Returns the name of the current state.
+    pub fn current(&self) -> String {
+        lock(&self.0.current).clone()
+    }
+
+    /// Creates a guard that is satisfied while this state machine is in the given state.
+    /// Use it with [`MethodCallBuilder::when`].
+    ///
+    /// [`MethodCallBuilder::when`]: crate::MethodCallBuilder::when
+    pub fn is(&self, state: &str) -> StateGuard {
+        StateGuard {
+            states: self.clone(),
+            state: state.to_string(),
+        }
+    }
+
+    /// Creates a transition of this state machine to the given state.
+    /// Use it with [`MethodCallBuilder::then_state`].
+    ///
+    /// [`MethodCallBuilder::then_state`]: crate::MethodCallBuilder::then_state
+    pub fn to(&self, state: &str) -> StateTransition {
+        StateTransition {
+            states: self.clone(),
+            state: state.to_string(),
+        }
+    }
+
+    pub(crate) fn is_same(&self, other: &States) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Display for States {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is {}", self.0.name, lock(&self.0.current))
+    }
+}
+
+/// Restricts an expected call to a state of a [`States`] machine.
+/// Guards are created using [`States::is`].
+#[derive(Clone, Debug)]
+pub struct StateGuard {
+    states: States,
+    state: String,
+}
+
+impl StateGuard {
+    pub(crate) fn is_satisfied(&self) -> bool {
+        *lock(&self.states.0.current) == self.state
+    }
+
+    pub(crate) fn states(&self) -> &States {
+        &self.states
+    }
+}
+
+impl Display for StateGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "when {} is {}", self.states.0.name, self.state)
+    }
+}
+
+/// Changes the state of a [`States`] machine when an expected call is called.
+/// Transitions are created using [`States::to`].
+#[derive(Clone, Debug)]
+pub struct StateTransition {
+    states: States,
+    state: String,
+}
+
+impl StateTransition {
+    pub(crate) fn apply(&self) {
+        *lock(&self.states.0.current) = self.state.clone();
+    }
+}
+
+impl Display for StateTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "then {} is {}", self.states.0.name, self.state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn guard_is_satisfied_in_its_state() {
+        let states = States::new("connection", "closed");
+
+        assert!(states.is("closed").is_satisfied());
+        assert!(!states.is("open").is_satisfied());
+    }
+
+    #[test]
+    fn transition_changes_state() {
+        let states = States::new("connection", "closed");
+        let guard = states.is("open");
+
+        states.to("open").apply();
+
+        assert_eq!("open", states.current());
+        assert!(guard.is_satisfied());
+    }
+
+    #[test]
+    fn states_are_displayed_with_current_state() {
+        let states = States::new("connection", "closed");
+
+        assert_eq!("connection is closed", states.to_string());
+        assert_eq!("when connection is open", states.is("open").to_string());
+        assert_eq!("then connection is open", states.to("open").to_string());
+    }
+}
@@ -0,0 +1,52 @@
+/// Decides how a mock handles calls that don't match any expected call.
+///
+/// The strictness is configured using `set_strictness` on a generated mock
+/// or for all instances of a mock using `#[mockable(nice)]`.
+///
+/// # Examples
+/// ```
+/// use mockiato::{mockable, Strictness};
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Logger {
+///     fn log(&self, message: &str);
+///     fn level(&self) -> u8;
+/// }
+///
+/// let mut logger = LoggerMock::new();
+/// logger.set_strictness(Strictness::Nice);
+///
+/// logger.log("Starting");
+/// assert_eq!(0, logger.level());
+///
+/// assert_eq!(2, logger.unexpected_calls().len());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strictness {
+    /// Unexpected calls panic. This is the default.
+    Strict,
+    /// Unexpected calls return [`Default::default`] and are recorded instead of panicking.
+    /// They can be inspected using `unexpected_calls` on a generated mock.
+    ///
+    /// Unexpected calls to methods whose return type does not implement [`Default`] still panic.
+    /// Calls that match an expected call but violate its constraints,
+    /// such as calls made out of sequence, panic as well.
+    Nice,
+    /// Calls that fail, e.g. because they were not expected or were made out of sequence,
+    /// record the failure and return [`Default::default`] instead of panicking.
+    /// The failures are reported when the mock is verified or dropped, which fails the test
+    /// even if the calls were made on another thread or inside of [`std::panic::catch_unwind`].
+    ///
+    /// Calls to methods whose return type does not implement [`Default`]
+    /// record the failure and panic.
+    Deferred,
+}
+
+impl Default for Strictness {
+    fn default() -> Self {
+        Strictness::Strict
+    }
+}
@@ -0,0 +1,392 @@
+use crate::internal::expected_calls::ExpectedCalls;
+use crate::internal::fmt::DisplayTimes;
+use crate::internal::location::DisplayDefinedAt;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::panic::Location;
+
+/// The methods of a mock whose expected calls were not called the expected number of times
+/// or whose calls did not complete a [`Grammar`].
+/// This report is returned by the `try_verify` method of a generated mock.
+///
+/// Reports of multiple mocks can be combined using [`MockVerificationReport::merge`].
+///
+/// # Examples
+/// ```
+/// use mockiato::mockable;
+///
+/// # const IGNORED: &str = "
+/// #[cfg_attr(test, mockable)]
+/// # ";
+/// # #[mockable]
+/// trait Greeter {
+///     fn greet(&self, name: &str) -> String;
+/// }
+///
+/// let mut greeter = GreeterMock::new();
+/// greeter
+///     .expect_greet(|arg| arg.partial_eq("Jane"))
+///     .returns(String::from("Hello Jane"));
+///
+/// let report = greeter.try_verify().unwrap_err();
+/// assert_eq!(1, report.failures().len());
+///
+/// let expected_call = &report.failures()[0].expected_calls()[0];
+/// assert_eq!(0, expected_call.call_count());
+/// assert!(!expected_call.is_satisfied());
+///
+/// greeter.disarm();
+/// ```
+///
+/// [`Grammar`]: crate::Grammar
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MockVerificationReport {
+    failures: Vec<MethodVerificationFailure>,
+}
+
+impl MockVerificationReport {
+    /// Creates a report without any failures.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the methods that failed verification.
+    pub fn failures(&self) -> &[MethodVerificationFailure] {
+        &self.failures
+    }
+
+    /// Returns `true` if no method failed verification.
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Adds the failures of another report to this report.
+    pub fn merge(&mut self, other: MockVerificationReport) {
+        for failure in other.failures {
+            self.add(failure);
+        }
+    }
+
+    /// Returns `Ok(())` if no method failed verification and the report otherwise.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Adds the failure of a method.
+    /// An incomplete grammar is only reported once, even if the calls to multiple methods are part of it.
+    pub(crate) fn add(&mut self, mut failure: MethodVerificationFailure) {
+        let failures = &self.failures;
+        failure.incomplete_grammars.retain(|grammar| {
+            !failures
+                .iter()
+                .any(|failure| failure.incomplete_grammars.contains(grammar))
+        });
+
+        if failure.is_unsatisfied()
+            || !failure.deferred_failures.is_empty()
+            || !failure.incomplete_grammars.is_empty()
+        {
+            self.failures.push(failure);
+        }
+    }
+}
+
+impl Display for MockVerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for failure in &self.failures {
+            write!(f, "{}", failure)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for MockVerificationReport {}
+
+/// A method whose expected calls were not called the expected number of times,
+/// whose calls failed while failures were deferred
+/// or whose calls did not complete a [`Grammar`].
+///
+/// [`Grammar`]: crate::Grammar
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodVerificationFailure {
+    method_name: &'static str,
+    expected_calls: Vec<ExpectedCallReport>,
+    deferred_failures: Vec<String>,
+    incomplete_grammars: Vec<String>,
+}
+
+impl MethodVerificationFailure {
+    pub(crate) fn new(
+        method_name: &'static str,
+        expected_calls: Vec<ExpectedCallReport>,
+        deferred_failures: Vec<String>,
+        incomplete_grammars: Vec<String>,
+    ) -> Self {
+        Self {
+            method_name,
+            expected_calls,
+            deferred_failures,
+            incomplete_grammars,
+        }
+    }
+
+    /// Returns the name of the method, including the module path of the mock.
+    pub fn method_name(&self) -> &str {
+        self.method_name
+    }
+
+    /// Returns every expected call of the method, including the satisfied ones.
+    pub fn expected_calls(&self) -> &[ExpectedCallReport] {
+        &self.expected_calls
+    }
+
+    /// Returns the calls that failed while failures were deferred using [`Strictness::Deferred`].
+    ///
+    /// [`Strictness::Deferred`]: crate::Strictness::Deferred
+    pub fn deferred_failures(&self) -> &[String] {
+        &self.deferred_failures
+    }
+
+    /// Returns the [`Grammar`]s the expected calls of the method are part of
+    /// that were not completed by the calls.
+    ///
+    /// [`Grammar`]: crate::Grammar
+    pub fn incomplete_grammars(&self) -> &[String] {
+        &self.incomplete_grammars
+    }
+
+    fn is_unsatisfied(&self) -> bool {
+        self.expected_calls
+            .iter()
+            .any(|expected_call| !expected_call.is_satisfied())
+    }
+}
+
+impl Display for MethodVerificationFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_unsatisfied() {
+            writeln!(
+                f,
+                "The expected calls for {} were not satisified.",
+                self.method_name
+            )?;
+
+            for expected_call in &self.expected_calls {
+                writeln!(f, "{}", expected_call)?;
+            }
+        }
+
+        if !self.deferred_failures.is_empty() {
+            writeln!(
+                f,
+                "The following calls to {} failed while failures were deferred:",
+                self.method_name
+            )?;
+
+            for failure in &self.deferred_failures {
+                writeln!(f, "{}", failure)?;
+            }
+        }
+
+        for grammar in &self.incomplete_grammars {
+            write!(f, "The calls did not complete the grammar.\n{}", grammar)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An expected call of a [`MethodVerificationFailure`], together with how often it was called.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpectedCallReport {
+    description: String,
+    expected_calls: ExpectedCalls,
+    call_count: u64,
+    location: Option<&'static Location<'static>>,
+}
+
+impl ExpectedCallReport {
+    pub(crate) fn new(
+        description: String,
+        expected_calls: ExpectedCalls,
+        call_count: u64,
+        location: Option<&'static Location<'static>>,
+    ) -> Self {
+        Self {
+            description,
+            expected_calls,
+            call_count,
+            location,
+        }
+    }
+
+    /// Returns the expected arguments and the return value of the expected call.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns how often the expected call is expected to be called.
+    pub fn expected_calls(&self) -> &ExpectedCalls {
+        &self.expected_calls
+    }
+
+    /// Returns how often the expected call was called.
+    pub fn call_count(&self) -> u64 {
+        self.call_count
+    }
+
+    /// Returns `true` if the expected call was called the expected number of times.
+    pub fn is_satisfied(&self) -> bool {
+        self.expected_calls.contains(self.call_count)
+    }
+
+    /// Returns where the expected call was defined.
+    /// Returns [`None`] when the compiler does not support `#[track_caller]`.
+    pub fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+}
+
+impl Display for ExpectedCallReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}, was called {}{}",
+            self.description,
+            self.expected_calls,
+            DisplayTimes(self.call_count),
+            DisplayDefinedAt(self.location)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn empty_report_is_ok() {
+        assert_eq!(Ok(()), MockVerificationReport::new().into_result());
+    }
+
+    fn failure(method_name: &'static str, description: &str) -> MethodVerificationFailure {
+        MethodVerificationFailure::new(
+            method_name,
+            vec![ExpectedCallReport::new(
+                description.to_string(),
+                ExpectedCalls::from(1),
+                0,
+                None,
+            )],
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn merge_combines_failures() {
+        let mut report = MockVerificationReport::new();
+        report.add(failure("FooMock::foo", "foo()"));
+        let mut other = MockVerificationReport::new();
+        other.add(failure("BarMock::bar", "bar()"));
+
+        report.merge(other);
+
+        let method_names: Vec<_> = report
+            .failures()
+            .iter()
+            .map(MethodVerificationFailure::method_name)
+            .collect();
+        assert_eq!(vec!["FooMock::foo", "BarMock::bar"], method_names);
+        assert_eq!(
+            2,
+            report
+                .to_string()
+                .lines()
+                .filter(|line| line.starts_with("The expected calls"))
+                .count()
+        );
+    }
+
+    #[test]
+    fn failure_is_displayed_from_expected_calls() {
+        let failure = MethodVerificationFailure::new(
+            "FooMock::foo",
+            vec![
+                ExpectedCallReport::new(
+                    String::from("foo(1) -> ()"),
+                    ExpectedCalls::from(2),
+                    1,
+                    None,
+                ),
+                ExpectedCallReport::new(
+                    String::from("foo(2) -> ()"),
+                    ExpectedCalls::from(1),
+                    1,
+                    None,
+                ),
+            ],
+            vec![String::from(
+                "On thread 'main':\nThe call FooMock::foo(3) was not expected.",
+            )],
+            Vec::new(),
+        );
+
+        assert_eq!(
+            "The expected calls for FooMock::foo were not satisified.\nfoo(1) -> () exactly 2 \
+             times, was called 1 time\nfoo(2) -> () exactly 1 time, was called 1 time\nThe \
+             following calls to FooMock::foo failed while failures were deferred:\nOn thread \
+             'main':\nThe call FooMock::foo(3) was not expected.\n",
+            failure.to_string()
+        );
+    }
+
+    #[test]
+    fn satisfied_expected_calls_are_not_displayed() {
+        let failure = MethodVerificationFailure::new(
+            "FooMock::foo",
+            vec![ExpectedCallReport::new(
+                String::from("foo(1) -> ()"),
+                ExpectedCalls::from(1),
+                1,
+                None,
+            )],
+            vec![String::from("failure")],
+            Vec::new(),
+        );
+
+        assert_eq!(
+            "The following calls to FooMock::foo failed while failures were deferred:\nfailure\n",
+            failure.to_string()
+        );
+    }
+
+    #[test]
+    fn incomplete_grammar_is_reported_once() {
+        let incomplete_grammar = || {
+            MethodVerificationFailure::new(
+                "ConnectionMock::connect",
+                Vec::new(),
+                Vec::new(),
+                vec![String::from("Grammar: connect() disconnect()\n")],
+            )
+        };
+        let mut report = MockVerificationReport::new();
+        report.add(incomplete_grammar());
+        report.add(MethodVerificationFailure {
+            method_name: "ConnectionMock::disconnect",
+            ..incomplete_grammar()
+        });
+
+        assert_eq!(1, report.failures().len());
+        assert_eq!(
+            "The calls did not complete the grammar.\nGrammar: connect() disconnect()\n",
+            report.to_string()
+        );
+    }
+}
@@ -25,7 +25,7 @@
 //!
 //! # Configuring Expected Calls
 //!
-//! Each method on the trait receives two companion methods on the mock struct:
+//! Each method on the trait receives the following companion methods on the mock struct:
 //!
 //! ## `expect_<method_name>`
 //!
@@ -56,6 +56,27 @@
 //!     .returns(());
 //! ```
 //!
+//! ## `allow_<method_name>`
+//!
+//! Allows calls to the mocked method any number of times, including zero.
+//! Allowed calls are never verified and are only used when no expected call matches.
+//! When more than one allowed call matches, the one that was added last is used.
+//!
+//! ```
+//! # use mockiato::mockable;
+//! #
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! # trait MessageSender {
+//! #     fn send_message(&self, recipient: &str, message: &str);
+//! # }
+//! #
+//! # let mut message_sender = MessageSenderMock::new();
+//! message_sender.allow_send_message(|arg| arg.any(), |arg| arg.any());
+//! ```
+//!
 //! ## `expect_<method_name>_calls_in_order`
 //!
 //! Configures the mocked method so that the expected calls are processed sequentially.
@@ -76,6 +97,27 @@
 //! message_sender.expect_send_message_calls_in_order();
 //! ```
 //!
+//! ## `set_<method_name>_ambiguity_resolution`
+//!
+//! Configures which expected call is used when a call matches more than one expected call.
+//! By default, such calls panic. See [`AmbiguityResolution`] for the available strategies.
+//! `set_ambiguity_resolution` configures all methods of a mock at once.
+//!
+//! ```
+//! # use mockiato::{mockable, AmbiguityResolution};
+//! #
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! # trait MessageSender {
+//! #     fn send_message(&self, recipient: &str, message: &str);
+//! # }
+//! #
+//! # let mut message_sender = MessageSenderMock::new();
+//! message_sender.set_send_message_ambiguity_resolution(AmbiguityResolution::MostSpecific);
+//! ```
+//!
 //! # Call Verification
 //! Mockiato automatically verifies that all expected calls were made when the mock goes out of scope.
 //! The mock panics when a method is called that was not configured, or if the parameters did not match.
@@ -106,6 +148,182 @@
 //!     // <--  and panics otherwise
 //! }
 //! ```
+//!
+//! Expected calls can also be verified before the mock goes out of scope using `checkpoint`.
+//! It verifies and then removes all expected calls, so that new ones can be defined.
+//! `phase` calls `checkpoint` once the given closure returns.
+//! ```
+//! # use mockiato::mockable;
+//! #
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! # trait Greeter {
+//! #     fn greet(&self, name: &str) -> String;
+//! # }
+//! #
+//! let mut greeter = GreeterMock::new();
+//!
+//! greeter.phase(|greeter| {
+//!     greeter
+//!         .expect_greet(|arg| arg.partial_eq("Jane"))
+//!         .returns(String::from("Hello Jane"));
+//!
+//!     assert_eq!("Hello Jane", greeter.greet("Jane"));
+//! });
+//!
+//! greeter
+//!     .expect_greet(|arg| arg.partial_eq("Jane"))
+//!     .returns(String::from("Hi Jane"));
+//!
+//! assert_eq!("Hi Jane", greeter.greet("Jane"));
+//! greeter.checkpoint();
+//! ```
+//!
+//! Calls can also be verified after they were made using `verify_<method_name>`.
+//! This works well with nice mocks, which accept any call.
+//! Arguments are recorded if they implement [`Clone`], or if they are references to a type implementing [`ToOwned`].
+//! Mocks generated with `#[mockable(sync)]` only record arguments that are [`Send`] and [`Sync`] as well.
+//! ```
+//! # use mockiato::{mockable, Strictness};
+//! #
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! # trait Greeter {
+//! #     fn greet(&self, name: &str) -> String;
+//! # }
+//! #
+//! let mut greeter = GreeterMock::new();
+//! greeter.set_strictness(Strictness::Nice);
+//!
+//! greeter.greet("Jane");
+//! greeter.greet("Jane");
+//!
+//! greeter
+//!     .verify_greet(|arg| arg.partial_eq("Jane"))
+//!     .times(2);
+//! ```
+//!
+//! When an unexpected call is made, the arguments are compared to the values expected by
+//! `partial_eq` matchers. The differences are printed as a line-based diff of the pretty-printed values.
+//! The diff is colored if stderr is a TTY. Set the `MOCKIATO_COLOR` environment variable
+//! to `always`, `never` or `auto` to override this.
+//!
+//! # Async Methods
+//! Methods declared as `async fn` or returning `impl Future<Output = T>` can be mocked.
+//! Expected calls are configured using the output type `T`.
+//! `returns_after_polls` defines a return value together with the number of polls
+//! for which the returned future is pending.
+//! Calls are recorded and matched when the method is called, not when the future is first polled.
+//!
+//! Traits using `#[async_trait]` are supported as well.
+//! If `#[mockable]` is applied before `#[async_trait]`, the mock's `async fn`s hold a reference to the mock
+//! across `.await` and make the call when the future is first polled.
+//! Use `#[mockable(sync)]` in this case if the futures must be [`Send`].
+//! ```
+//! use mockiato::mockable;
+//!
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! trait UserRepository {
+//!     async fn name(&self, id: u32) -> String;
+//! }
+//!
+//! let mut user_repository = UserRepositoryMock::new();
+//!
+//! user_repository
+//!     .expect_name(|arg| arg.partial_eq(1))
+//!     .returns_after_polls(2, String::from("Jane"));
+//! # user_repository.disarm();
+//! ```
+//!
+//! # Generic Methods
+//! Methods with type parameters can be mocked if the type parameters are `'static`.
+//! The `'static` bound must be written out explicitly,
+//! e.g. `fn get<T: DeserializeOwned>` needs to be declared as `fn get<T: DeserializeOwned + 'static>`.
+//! Expected calls are defined separately for every combination of type arguments.
+//! The type arguments are inferred from the argument matchers and the return value.
+//! They can also be given explicitly, followed by a `_` for each argument matcher,
+//! e.g. `expect_get::<User, _>(..)`.
+//!
+//! Arguments of a generic type are recorded for `verify_<method_name>`
+//! if the type parameter is bounded by `Clone + Send + Sync`.
+//! ```
+//! use mockiato::mockable;
+//!
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! trait Settings {
+//!     fn get<T: 'static>(&self, key: &str) -> T;
+//! }
+//!
+//! let mut settings = SettingsMock::new();
+//!
+//! settings
+//!     .expect_get(|arg| arg.partial_eq("retries"))
+//!     .returns(3_u32);
+//! settings
+//!     .expect_get::<String, _>(|arg| arg.partial_eq("name"))
+//!     .returns(String::from("Jane"));
+//!
+//! assert_eq!(3_u32, settings.get("retries"));
+//! assert_eq!("Jane", settings.get::<String>("name"));
+//! ```
+//!
+//! # Associated Types
+//! Associated types of a mockable trait are bound by type parameters of the mock
+//! with the same name and bounds. They follow the type parameters of the trait.
+//! Generic associated types are not supported.
+//! ```
+//! use mockiato::mockable;
+//!
+//! # const IGNORED: &str = "
+//! #[cfg_attr(test, mockable)]
+//! # ";
+//! # #[mockable]
+//! trait Source {
+//!     type Item;
+//!
+//!     fn next(&mut self) -> Option<Self::Item>;
+//! }
+//!
+//! let mut source = SourceMock::<u32>::new();
+//!
+//! source.expect_next().returns(Some(1));
+//!
+//! assert_eq!(Some(1), source.next());
+//! ```
+//!
+//! # Associated Consts
+//! Associated consts keep the default value of the trait.
+//! Consts without a default value need a value given by `#[mockable(const NAME = ...)]`,
+//! which can also be used to override a default value.
+//! Const generic parameters of the trait are const generic parameters of the mock as well.
+//! ```
+//! use mockiato::mockable;
+//!
+//! # const IGNORED: &str = r#"
+//! #[cfg_attr(test, mockable(const NAME = "fake"))]
+//! # "#;
+//! # #[mockable(const NAME = "fake")]
+//! trait Codec {
+//!     const NAME: &'static str;
+//!
+//!     const VERSION: u32 = 1;
+//!
+//!     fn encode(&self, value: u32) -> Vec<u8>;
+//! }
+//!
+//! assert_eq!("fake", <CodecMock<'_> as Codec>::NAME);
+//! assert_eq!(1, <CodecMock<'_> as Codec>::VERSION);
+//! ```
 
 #![cfg_attr(rustc_is_nightly, feature(doc_cfg, external_doc, specialization))]
 #![warn(missing_docs, clippy::dbg_macro, clippy::unimplemented)]
@@ -157,6 +375,57 @@ pub use mockiato_codegen::mockable;
 ///     fn make_sound(&self);
 /// }
 /// ```
+///
+/// ## `default_returns`
+/// Expected calls that don't specify a return value return [`Default::default`]
+/// when the method's return type implements [`Default`].
+/// Return values specified using `returns` take precedence.
+/// The `expect_*` methods are still `#[must_use]`. Use `let _ = ` to ignore the returned builder.
+/// ```
+/// use mockiato::mockable;
+///
+/// #[cfg_attr(test, mockable(default_returns))]
+/// trait UserRepository {
+///     fn user_count(&self) -> usize;
+/// }
+/// ```
+///
+/// ## `nice`
+/// Unexpected calls return [`Default::default`] instead of panicking
+/// when the method's return type implements [`Default`]. See [`Strictness::Nice`].
+/// ```
+/// use mockiato::mockable;
+///
+/// #[cfg_attr(test, mockable(nice))]
+/// trait Logger {
+///     fn log(&self, message: &str);
+/// }
+/// ```
+///
+/// ## `sync`
+/// Argument matchers must be [`Send`] and [`Sync`], which makes the mock [`Send`] and [`Sync`]
+/// when the return types of the trait's methods are as well.
+/// This allows the mock to be shared between threads, e.g. as `Arc<dyn Trait + Send + Sync>`.
+/// ```
+/// use mockiato::mockable;
+///
+/// #[cfg_attr(test, mockable(sync))]
+/// trait MessageQueue: Send + Sync {
+///     fn push(&self, message: String);
+/// }
+/// ```
+///
+/// ## `const`
+/// Sets the value of an associated const of the mock.
+/// Required for associated consts without a default value.
+/// ```
+/// use mockiato::mockable;
+///
+/// #[cfg_attr(test, mockable(const NAME = "fake"))]
+/// trait Codec {
+///     const NAME: &'static str;
+/// }
+/// ```
 macro_rules! mockable {
     () => {};
 }
@@ -164,9 +433,19 @@ macro_rules! mockable {
 #[cfg_attr(rustc_is_nightly, doc(include = "../readme.md"))]
 mod test_readme {}
 
+pub use crate::internal::ambiguity_resolution::AmbiguityResolution;
 pub use crate::internal::argument::Argument;
+pub use crate::internal::expectation::ExpectationHandle;
 pub use crate::internal::expected_calls::ExpectedCalls;
-pub use crate::internal::MethodCallBuilder;
+pub use crate::internal::grammar::{Grammar, Rule};
+pub use crate::internal::recorded_call::RecordedCall;
+pub use crate::internal::sequence::Sequence;
+pub use crate::internal::states::{StateGuard, StateTransition, States};
+pub use crate::internal::strictness::Strictness;
+pub use crate::internal::verification_report::{
+    ExpectedCallReport, MethodVerificationFailure, MockVerificationReport,
+};
+pub use crate::internal::{CallVerification, MethodCallBuilder};
 
 #[doc(hidden)]
 pub mod internal;
@@ -0,0 +1,56 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Transaction {
+    fn write(&self, data: &str);
+    fn commit(&self);
+}
+
+#[mockable]
+trait Logger {
+    fn log(&self, message: &str);
+}
+
+#[test]
+fn prerequisites_can_be_satisfied_in_any_order() {
+    let mut transaction = TransactionMock::new();
+    let first_write = transaction
+        .expect_write(|arg| arg.partial_eq("foo"))
+        .handle();
+    let second_write = transaction
+        .expect_write(|arg| arg.partial_eq("bar"))
+        .handle();
+    transaction
+        .expect_commit()
+        .after(&first_write)
+        .after(&second_write);
+
+    transaction.write("bar");
+    transaction.write("foo");
+    transaction.commit();
+}
+
+#[test]
+fn prerequisites_work_across_mocks() {
+    let mut transaction = TransactionMock::new();
+    let mut logger = LoggerMock::new();
+    let commit = transaction.expect_commit().handle();
+    logger.expect_log(|arg| arg.any()).after(&commit);
+
+    transaction.commit();
+    logger.log("committed");
+}
+
+#[test]
+#[should_panic(
+    expected = "The call after::TransactionMock::commit() was made before the following expected \
+                call was satisfied:\nwrite(*) exactly 2 times, was called 1 time"
+)]
+fn call_before_prerequisite_is_satisfied_panics() {
+    let mut transaction = TransactionMock::new();
+    let writes = transaction.expect_write(|arg| arg.any()).times(2).handle();
+    transaction.expect_commit().after(&writes);
+
+    transaction.write("foo");
+    transaction.commit();
+}
@@ -0,0 +1,82 @@
+use mockiato::{mockable, Strictness};
+
+#[mockable]
+trait UserRepository {
+    fn get(&self, name: &str) -> Option<u32>;
+    fn log(&self, message: &str);
+}
+
+#[test]
+fn allowed_calls_can_be_made_any_number_of_times() {
+    let mut repository = UserRepositoryMock::new();
+    repository.allow_get(|arg| arg.any()).returns(Some(1));
+
+    assert_eq!(Some(1), repository.get("foo"));
+    assert_eq!(Some(1), repository.get("bar"));
+}
+
+#[test]
+fn allowed_calls_are_not_verified() {
+    let mut repository = UserRepositoryMock::new();
+    repository.allow_get(|arg| arg.any()).returns(Some(1));
+    repository.allow_log(|arg| arg.any());
+}
+
+#[test]
+fn expected_calls_take_precedence_over_allowed_calls() {
+    let mut repository = UserRepositoryMock::new();
+    repository.allow_get(|arg| arg.any()).returns(None);
+    repository
+        .expect_get(|arg| arg.partial_eq("admin"))
+        .returns(Some(0));
+
+    assert_eq!(Some(0), repository.get("admin"));
+    assert_eq!(None, repository.get("admin"));
+    assert_eq!(None, repository.get("guest"));
+}
+
+#[test]
+fn most_recently_allowed_call_is_used() {
+    let mut repository = UserRepositoryMock::new();
+    repository.allow_get(|arg| arg.any()).returns(None);
+    repository
+        .allow_get(|arg| arg.partial_eq("admin"))
+        .returns(Some(0));
+
+    assert_eq!(Some(0), repository.get("admin"));
+    assert_eq!(None, repository.get("guest"));
+}
+
+#[test]
+fn allowed_calls_are_not_recorded_as_unexpected_calls() {
+    let mut repository = UserRepositoryMock::new();
+    repository.set_strictness(Strictness::Nice);
+    repository.allow_log(|arg| arg.partial_eq("foo"));
+
+    repository.log("foo");
+    repository.log("bar");
+
+    assert_eq!(
+        vec!["allow::UserRepositoryMock::log(\"bar\")"],
+        repository.unexpected_calls()
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "The call allow::UserRepositoryMock::get(\"guest\") was not expected.\nThe \
+                following calls were expected:\nget(*) -> Some(0) exactly 0 times, was called 0 \
+                times"
+)]
+fn allowed_calls_are_not_listed_as_expected_calls() {
+    let mut repository = UserRepositoryMock::new();
+    repository
+        .allow_get(|arg| arg.partial_eq("user"))
+        .returns(Some(1));
+    repository
+        .expect_get(|arg| arg.any())
+        .times(0)
+        .returns(Some(0));
+
+    repository.get("guest");
+}
@@ -0,0 +1,127 @@
+use mockiato::{mockable, AmbiguityResolution};
+
+#[mockable]
+trait UserRepository {
+    fn get(&self, name: &str, role: &str) -> Option<u32>;
+    fn exists(&self, name: &str) -> bool;
+}
+
+#[test]
+fn most_specific_expected_call_is_used() {
+    let mut repository = UserRepositoryMock::new();
+    repository.set_get_ambiguity_resolution(AmbiguityResolution::MostSpecific);
+
+    repository
+        .expect_get(|arg| arg.any(), |arg| arg.any())
+        .times(..)
+        .returns(None);
+    repository
+        .expect_get(|arg| arg.partial_eq("admin"), |arg| arg.partial_eq("root"))
+        .returns(Some(0));
+    repository
+        .expect_get(|arg| arg.partial_eq("admin"), |arg| arg.any())
+        .returns(Some(1));
+
+    assert_eq!(Some(0), repository.get("admin", "root"));
+    assert_eq!(Some(1), repository.get("admin", "user"));
+    assert_eq!(None, repository.get("guest", "user"));
+}
+
+#[test]
+fn less_specific_expected_call_is_used_once_more_specific_call_is_saturated() {
+    let mut repository = UserRepositoryMock::new();
+    repository.set_get_ambiguity_resolution(AmbiguityResolution::MostSpecific);
+
+    repository
+        .expect_get(|arg| arg.any(), |arg| arg.any())
+        .returns(None);
+    repository
+        .expect_get(|arg| arg.partial_eq("admin"), |arg| arg.any())
+        .returns(Some(1));
+
+    assert_eq!(Some(1), repository.get("admin", "root"));
+    assert_eq!(None, repository.get("admin", "root"));
+}
+
+#[test]
+#[should_panic(expected = "matches more than one expected call")]
+fn equally_specific_expected_calls_are_ambiguous() {
+    let mut repository = UserRepositoryMock::new();
+    repository.set_get_ambiguity_resolution(AmbiguityResolution::MostSpecific);
+
+    repository
+        .expect_get(|arg| arg.partial_eq("admin"), |arg| arg.any())
+        .returns(Some(0));
+    repository
+        .expect_get(|arg| arg.any(), |arg| arg.partial_eq("root"))
+        .returns(Some(1));
+
+    repository.get("admin", "root");
+}
+
+#[test]
+fn most_recently_defined_expected_call_is_used() {
+    let mut repository = UserRepositoryMock::new();
+    repository.set_ambiguity_resolution(AmbiguityResolution::MostRecentlyDefined);
+
+    repository
+        .expect_exists(|arg| arg.any())
+        .times(..)
+        .returns(false);
+    repository
+        .expect_exists(|arg| arg.partial_eq("admin"))
+        .returns(true);
+
+    assert!(repository.exists("admin"));
+}
+
+#[test]
+#[should_panic(
+    expected = "The call ambiguity_resolution::UserRepositoryMock::exists(\"admin\") was not \
+                expected."
+)]
+fn most_recently_defined_expected_call_is_used_even_when_saturated() {
+    let mut repository = UserRepositoryMock::new();
+    repository.set_ambiguity_resolution(AmbiguityResolution::MostRecentlyDefined);
+
+    repository
+        .expect_exists(|arg| arg.any())
+        .times(..)
+        .returns(false);
+    repository
+        .expect_exists(|arg| arg.partial_eq("admin"))
+        .returns(true);
+
+    repository.exists("admin");
+    repository.exists("admin");
+}
+
+#[test]
+fn first_unsaturated_expected_call_is_used() {
+    let mut repository = UserRepositoryMock::new();
+    repository.set_exists_ambiguity_resolution(AmbiguityResolution::FirstUnsaturated);
+
+    repository
+        .expect_exists(|arg| arg.partial_eq("admin"))
+        .returns(true);
+    repository
+        .expect_exists(|arg| arg.any())
+        .times(..)
+        .returns(false);
+
+    assert!(repository.exists("admin"));
+    assert!(!repository.exists("admin"));
+}
+
+#[test]
+#[should_panic(expected = "matches more than one expected call")]
+fn ambiguous_calls_panic_by_default() {
+    let mut repository = UserRepositoryMock::new();
+
+    repository
+        .expect_exists(|arg| arg.partial_eq("admin"))
+        .returns(true);
+    repository.expect_exists(|arg| arg.any()).returns(false);
+
+    repository.exists("admin");
+}
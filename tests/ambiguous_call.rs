@@ -1,20 +1,47 @@
 use mockiato::mockable;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 
 #[mockable]
 trait MessageSender {
     fn ping(&self);
 }
 
-#[cfg(rustc_is_nightly)]
 #[test]
-#[should_panic(
-    expected = "The call MessageSenderMock::ping() matches more than one expected call:\nping() \
-                -> () exactly 1 time, was called 0 times\nping() -> () exactly 1 time, was called \
-                0 times"
-)]
 fn panics_on_more_than_one_matching_call() {
     let mut message_sender = MessageSenderMock::new();
     message_sender.expect_ping().times(1);
+    let first_definition = line!() - 1;
     message_sender.expect_ping().times(1);
-    message_sender.ping();
+    let second_definition = line!() - 1;
+
+    let message = panic_message(|| message_sender.ping());
+
+    let expected = format!(
+        "The call ambiguous_call::MessageSenderMock::ping() matches more than one expected \
+         call:\nping() -> () exactly 1 time, was called 0 times{}\nping() -> () exactly 1 time, \
+         was called 0 times{}",
+        defined_at(first_definition),
+        defined_at(second_definition)
+    );
+    assert!(message.contains(&expected), "{}", message);
+
+    message_sender.disarm();
+}
+
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = catch_unwind(AssertUnwindSafe(f)).expect_err("Expected a panic");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .expect("Expected a formatted panic message")
+}
+
+#[cfg(rustc_has_track_caller)]
+fn defined_at(line: u32) -> String {
+    format!(" (defined at {}:{})", file!(), line)
+}
+
+#[cfg(not(rustc_has_track_caller))]
+fn defined_at(_line: u32) -> String {
+    String::new()
 }
@@ -0,0 +1,69 @@
+use mockiato::mockable;
+
+#[mockable(const NAME = "fake")]
+trait Codec {
+    const NAME: &'static str;
+
+    const VERSION: u32 = 1;
+
+    fn encode(&self, value: u32) -> Vec<u8>;
+}
+
+#[mockable]
+trait Limits {
+    const MAX_RETRIES: usize = 3;
+
+    fn retries(&self) -> usize;
+}
+
+#[cfg(rustc_has_min_const_generics)]
+#[mockable(static_references, const LEN = N * 2)]
+trait Buffer<const N: usize> {
+    const LEN: usize;
+
+    fn fill(&self, bytes: [u8; N]) -> [u8; N];
+}
+
+fn codec_name<C: Codec>(_codec: &C) -> &'static str {
+    C::NAME
+}
+
+#[test]
+fn associated_consts_can_be_set_using_the_attribute() {
+    let codec = CodecMock::new();
+
+    assert_eq!("fake", codec_name(&codec));
+    assert_eq!("fake", <CodecMock<'_> as Codec>::NAME);
+}
+
+#[test]
+fn associated_consts_keep_the_default_of_the_trait() {
+    let mut limits = LimitsMock::new();
+    limits.expect_retries().returns(LimitsMock::MAX_RETRIES);
+
+    assert_eq!(3, <LimitsMock<'_> as Limits>::MAX_RETRIES);
+    assert_eq!(1, <CodecMock<'_> as Codec>::VERSION);
+    assert_eq!(3, limits.retries());
+}
+
+#[test]
+fn methods_of_traits_with_associated_consts_can_be_mocked() {
+    let mut codec = CodecMock::new();
+    codec
+        .expect_encode(|arg| arg.partial_eq(7))
+        .returns(vec![7]);
+
+    assert_eq!(vec![7], codec.encode(7));
+}
+
+#[cfg(rustc_has_min_const_generics)]
+#[test]
+fn traits_with_const_generics_can_be_mocked() {
+    let mut buffer = BufferMock::<4>::new();
+    buffer
+        .expect_fill(|arg| arg.partial_eq([1, 2, 3, 4]))
+        .returns([0; 4]);
+
+    assert_eq!(8, <BufferMock<'static, 4> as Buffer<4>>::LEN);
+    assert_eq!([0; 4], buffer.fill([1, 2, 3, 4]));
+}
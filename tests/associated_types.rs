@@ -0,0 +1,85 @@
+use mockiato::mockable;
+use std::fmt::Debug;
+
+#[derive(Debug, PartialEq, Clone)]
+struct NotFound;
+
+#[mockable]
+trait Source {
+    type Item: Debug;
+
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+#[mockable]
+trait Repository {
+    type Key: Debug + PartialEq;
+    type Error;
+
+    fn get(&self, key: Self::Key) -> Result<String, Self::Error>;
+
+    fn contains(&self, key: <Self as Repository>::Key) -> bool;
+}
+
+#[mockable]
+trait Unused {
+    type Marker: ?Sized;
+
+    fn count(&self) -> usize;
+}
+
+fn collect<S>(source: &mut S) -> Vec<S::Item>
+where
+    S: Source,
+{
+    let mut items = Vec::new();
+
+    while let Some(item) = source.next() {
+        items.push(item);
+    }
+
+    items
+}
+
+#[test]
+fn associated_types_are_bound_by_type_parameters() {
+    let mut source = SourceMock::<u32>::new();
+    source.expect_next().returns(Some(1)).times(2);
+    source.expect_next().returns(None);
+    source.expect_next_calls_in_order();
+
+    assert_eq!(vec![1, 1], collect(&mut source));
+}
+
+#[test]
+fn associated_types_can_be_used_in_arguments() {
+    let mut repository = RepositoryMock::<u32, NotFound>::new();
+    repository
+        .expect_get(|arg| arg.partial_eq(1))
+        .returns(Ok(String::from("one")));
+    repository
+        .expect_get(|arg| arg.partial_eq(2))
+        .returns(Err(NotFound));
+    repository
+        .expect_contains(|arg| arg.partial_eq(3))
+        .returns(false);
+
+    assert_eq!(Ok(String::from("one")), repository.get(1));
+    assert_eq!(Err(NotFound), repository.get(2));
+    assert!(!repository.contains(3));
+}
+
+#[test]
+#[should_panic(expected = "The call associated_types::RepositoryMock::get(4) was not expected.")]
+fn unexpected_calls_are_formatted_with_associated_types() {
+    let repository = RepositoryMock::<u32, NotFound>::new();
+    let _ = repository.get(4);
+}
+
+#[test]
+fn associated_types_do_not_need_to_be_used_by_methods() {
+    let mut unused = UnusedMock::<str>::new();
+    unused.expect_count().returns(3);
+
+    assert_eq!(3, unused.count());
+}
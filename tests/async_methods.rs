@@ -0,0 +1,163 @@
+#![cfg(rustc_has_async_fn_in_trait)]
+
+use mockiato::mockable;
+use std::future::Future;
+use std::pin::Pin;
+use std::ptr;
+use std::sync::Arc;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+#[mockable]
+trait UserService {
+    async fn name(&self, id: u32) -> String;
+
+    async fn delete(&self, id: u32);
+
+    fn exists(&self, id: u32) -> impl Future<Output = bool>;
+}
+
+/// Declared like the desugared output of `#[async_trait]`.
+#[mockable(sync)]
+trait Greeter: Send + Sync {
+    fn greet(&self, name: &str) -> Pin<Box<dyn Future<Output = String> + Send + '_>>;
+}
+
+#[mockable]
+trait Storage {
+    fn load(&self, key: &str) -> Pin<Box<dyn Future<Output = Option<u32>> + Send + '_>>;
+}
+
+/// Polls the future until it is ready and returns its output
+/// together with the number of times it was pending.
+fn block_on<F>(future: F) -> (F::Output, u32)
+where
+    F: Future,
+{
+    let waker = noop_waker();
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    let mut pending_polls = 0;
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return (output, pending_polls),
+            Poll::Pending => pending_polls += 1,
+        }
+    }
+}
+
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(ptr::null(), &VTABLE)
+    }
+
+    fn noop(_: *const ()) {}
+
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+    unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+}
+
+#[test]
+fn async_methods_return_configured_output() {
+    let mut user_service = UserServiceMock::new();
+    user_service
+        .expect_name(|arg| arg.partial_eq(1))
+        .returns(String::from("Jane"));
+
+    let (name, pending_polls) = block_on(user_service.name(1));
+
+    assert_eq!("Jane", name);
+    assert_eq!(0, pending_polls);
+}
+
+#[test]
+fn async_methods_returning_unit_do_not_need_return_value() {
+    let mut user_service = UserServiceMock::new();
+    user_service.expect_delete(|arg| arg.partial_eq(1));
+
+    block_on(user_service.delete(1));
+}
+
+#[test]
+fn returns_after_polls_keeps_future_pending() {
+    let mut user_service = UserServiceMock::new();
+    user_service
+        .expect_name(|arg| arg.any())
+        .returns_after_polls(3, String::from("Paul"));
+
+    let (name, pending_polls) = block_on(user_service.name(2));
+
+    assert_eq!("Paul", name);
+    assert_eq!(3, pending_polls);
+}
+
+#[test]
+fn async_methods_are_called_before_the_future_is_polled() {
+    let mut user_service = UserServiceMock::new();
+    user_service
+        .expect_name(|arg| arg.partial_eq(1))
+        .returns(String::from("Jane"));
+
+    let name = user_service.name(1);
+    assert_eq!(1, user_service.calls_to_name().len());
+
+    assert_eq!((String::from("Jane"), 0), block_on(name));
+}
+
+#[test]
+fn impl_future_methods_are_called_before_the_future_is_polled() {
+    let mut user_service = UserServiceMock::new();
+    user_service
+        .expect_exists(|arg| arg.partial_eq(1))
+        .returns_after_polls(1, true);
+
+    let exists = user_service.exists(1);
+    assert_eq!(1, user_service.calls_to_exists().len());
+
+    assert_eq!((true, 1), block_on(exists));
+}
+
+#[test]
+#[should_panic(expected = "The call async_methods::UserServiceMock::name(3) was not expected.")]
+fn unexpected_calls_panic_when_polled() {
+    let user_service = UserServiceMock::new();
+
+    block_on(user_service.name(3));
+}
+
+#[test]
+fn calls_to_async_methods_can_be_verified() {
+    let mut user_service = UserServiceMock::new();
+    user_service.allow_delete(|arg| arg.any());
+
+    block_on(user_service.delete(4));
+
+    user_service.verify_delete(|arg| arg.partial_eq(4));
+}
+
+#[test]
+fn boxed_future_methods_can_be_mocked() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns_after_polls(2, String::from("Hello Jane"));
+    let greeter: Arc<dyn Greeter> = Arc::new(greeter);
+
+    let (greeting, pending_polls) = block_on(greeter.greet("Jane"));
+
+    assert_eq!("Hello Jane", greeting);
+    assert_eq!(2, pending_polls);
+}
+
+#[test]
+fn boxed_future_methods_of_traits_that_are_not_sync_can_be_mocked() {
+    let mut storage = StorageMock::new();
+    storage
+        .expect_load(|arg| arg.partial_eq("answer"))
+        .returns(Some(42));
+
+    let future: Pin<Box<dyn Future<Output = Option<u32>> + Send>> = storage.load("answer");
+
+    assert_eq!((Some(42), 0), block_on(future));
+}
@@ -0,0 +1,108 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+    fn wave(&self);
+}
+
+#[test]
+fn checkpoint_removes_expected_calls() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .returns(String::from("Hello"));
+    assert_eq!("Hello", greeter.greet("Jane"));
+
+    greeter.checkpoint();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .returns(String::from("Hi"));
+    assert_eq!("Hi", greeter.greet("Jane"));
+}
+
+#[test]
+fn phase_verifies_expected_calls_defined_in_closure() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.phase(|greeter| {
+        greeter
+            .expect_greet(|arg| arg.any())
+            .returns(String::from("Hello"));
+        assert_eq!("Hello", greeter.greet("Jane"));
+    });
+}
+
+#[test]
+#[should_panic(
+    expected = "The expected calls for checkpoint::GreeterMock::wave were not satisified."
+)]
+fn checkpoint_panics_when_expected_calls_were_not_made() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_wave();
+
+    greeter.checkpoint();
+}
+
+#[test]
+#[should_panic(
+    expected = "The expected calls for checkpoint::GreeterMock::greet were not satisified."
+)]
+fn phase_panics_when_expected_calls_were_not_made() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.phase(|greeter| {
+        greeter
+            .expect_greet(|arg| arg.any())
+            .returns(String::from("Hello"));
+    });
+}
+
+#[test]
+#[should_panic(expected = "The call checkpoint::GreeterMock::wave() was not expected.")]
+fn calls_expected_before_checkpoint_are_no_longer_expected() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.phase(|greeter| {
+        greeter.expect_wave().times(..);
+    });
+
+    greeter.wave();
+}
+
+#[test]
+#[should_panic(expected = "The call checkpoint::GreeterMock::wave() was not expected.")]
+fn checkpoint_removes_stubs() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.allow_wave();
+    greeter.checkpoint();
+
+    greeter.wave();
+}
+
+#[test]
+fn checkpoint_resets_order_of_expected_calls() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.phase(|greeter| {
+        greeter.expect_greet_calls_in_order();
+        greeter
+            .expect_greet(|arg| arg.partial_eq("Jane"))
+            .returns(String::from("Hello"));
+        assert_eq!("Hello", greeter.greet("Jane"));
+    });
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .returns(String::from("Hello"));
+    greeter
+        .expect_greet(|arg| arg.partial_eq("John"))
+        .returns(String::from("Hi"));
+
+    assert_eq!("Hi", greeter.greet("John"));
+    assert_eq!("Hello", greeter.greet("Jane"));
+}
@@ -0,0 +1,44 @@
+use mockiato::mockable;
+
+#[derive(Debug)]
+struct Person {
+    name: &'static str,
+}
+
+struct Secret;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, person: Person) -> String;
+
+    fn whisper(&self, secret: Secret) -> Secret;
+}
+
+#[test]
+#[should_panic(
+    expected = "The call debug_output::GreeterMock::greet(Person { name: \"John\" }) was not \
+                expected."
+)]
+fn arguments_implementing_debug_are_displayed() {
+    let greeter = GreeterMock::new();
+
+    greeter.greet(Person { name: "John" });
+}
+
+#[test]
+#[should_panic(expected = "The call debug_output::GreeterMock::whisper(?) was not expected.")]
+fn arguments_not_implementing_debug_are_displayed_as_placeholder() {
+    let greeter = GreeterMock::new();
+
+    greeter.whisper(Secret);
+}
+
+#[test]
+#[should_panic(expected = "-> \"Hello John\" exactly 1 time, was called 0 times")]
+fn return_values_implementing_debug_are_displayed() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.any())
+        .returns(String::from("Hello John"));
+}
@@ -0,0 +1,40 @@
+use mockiato::mockable;
+
+struct Secret;
+
+#[mockable(default_returns)]
+trait Repository {
+    fn name(&self) -> String;
+
+    fn count(&self, filter: &str) -> usize;
+
+    fn secret(&self) -> Secret;
+}
+
+#[test]
+fn expectations_without_return_value_return_default() {
+    let mut repository = RepositoryMock::new();
+    let _ = repository.expect_name();
+    let _ = repository.expect_count(|arg| arg.any());
+
+    assert_eq!(String::new(), repository.name());
+    assert_eq!(0, repository.count("foo"));
+}
+
+#[test]
+fn explicit_return_values_are_used() {
+    let mut repository = RepositoryMock::new();
+    repository.expect_name().returns(String::from("users"));
+    repository.expect_count(|arg| arg.any()).returns(42);
+
+    assert_eq!("users", repository.name());
+    assert_eq!(42, repository.count("foo"));
+}
+
+#[test]
+#[should_panic(expected = "The expected call secret() of \
+                           default_returns_attr::RepositoryMock::secret has no return value")]
+fn return_types_without_default_need_return_value() {
+    let mut repository = RepositoryMock::new();
+    let _ = repository.expect_secret();
+}
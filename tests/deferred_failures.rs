@@ -0,0 +1,97 @@
+use mockiato::{mockable, Strictness};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use std::thread;
+
+struct Secret;
+
+#[mockable(sync)]
+trait Counter: Send + Sync {
+    fn increment(&self, name: &str) -> usize;
+
+    fn secret(&self) -> Secret;
+}
+
+#[test]
+fn unexpected_calls_return_default_value() {
+    let mut counter = CounterMock::new();
+    counter.set_strictness(Strictness::Deferred);
+
+    assert_eq!(0, counter.increment("foo"));
+
+    counter.disarm();
+}
+
+#[test]
+fn expected_calls_are_still_used() {
+    let mut counter = CounterMock::new();
+    counter.set_strictness(Strictness::Deferred);
+    counter
+        .expect_increment(|arg| arg.partial_eq("foo"))
+        .returns(3);
+
+    assert_eq!(3, counter.increment("foo"));
+}
+
+#[test]
+#[should_panic(
+    expected = "The call deferred_failures::CounterMock::increment(\"bar\") was not expected."
+)]
+fn unexpected_calls_fail_verification() {
+    let mut counter = CounterMock::new();
+    counter.set_strictness(Strictness::Deferred);
+
+    counter.increment("bar");
+}
+
+#[test]
+#[should_panic(expected = "failed while failures were deferred")]
+fn failures_on_other_threads_are_reported_on_drop() {
+    let mut counter = CounterMock::new();
+    counter.set_strictness(Strictness::Deferred);
+
+    let counter = Arc::new(counter);
+
+    thread::spawn({
+        let counter = counter.clone();
+        move || {
+            counter.increment("bar");
+        }
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn failures_inside_catch_unwind_are_reported_by_try_verify() {
+    let mut counter = CounterMock::new();
+    counter.set_strictness(Strictness::Deferred);
+
+    let result = catch_unwind(AssertUnwindSafe(|| counter.secret()));
+    assert!(result.is_err());
+
+    let report = counter.try_verify().unwrap_err();
+    assert_eq!(1, report.failures().len());
+    assert!(report
+        .to_string()
+        .contains("The call deferred_failures::CounterMock::secret() was not expected."));
+
+    counter.disarm();
+}
+
+#[test]
+fn expected_calls_missing_and_deferred_failures_are_both_reported() {
+    let mut counter = CounterMock::new();
+    counter.set_strictness(Strictness::Deferred);
+    counter
+        .expect_increment(|arg| arg.partial_eq("foo"))
+        .returns(3);
+
+    counter.increment("bar");
+
+    let report = counter.try_verify().unwrap_err().to_string();
+    assert!(report.contains("The expected calls for deferred_failures::CounterMock::increment"));
+    assert!(report.contains("The call deferred_failures::CounterMock::increment(\"bar\")"));
+
+    counter.disarm();
+}
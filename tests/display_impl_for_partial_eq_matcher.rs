@@ -32,10 +32,10 @@ fn partial_eq_matcher_has_display_output() {
 
 #[cfg(rustc_is_nightly)]
 #[test]
-#[should_panic(
-    expected = "The expected calls for GreeterMock::greet were not satisified.
-greet(Person { name: \"Name\", age: 30 }) -> \"Hello Name\" exactly 2 times, was called 0 times"
-)]
+#[should_panic(expected = "The expected calls for \
+                           display_impl_for_partial_eq_matcher::GreeterMock::greet were not \
+                           satisified.
+greet(Person { name: \"Name\", age: 30 }) -> \"Hello Name\" exactly 2 times, was called 0 times")]
 fn partial_eq_matcher_has_display_output_when_printed_as_expected_call() {
     let mut greeter = GreeterMock::new();
     greeter
@@ -52,7 +52,7 @@ fn partial_eq_matcher_has_display_output_when_printed_as_expected_call() {
 #[cfg(rustc_is_nightly)]
 #[test]
 #[should_panic(
-    expected = "The expected calls for GreeterMock::greet_ref were not satisified.
+    expected = "The expected calls for display_impl_for_partial_eq_matcher::GreeterMock::greet_ref were not satisified.
 greet_ref(Person { name: \"Name\", age: 30 }) -> \"Hello Name\" exactly 2 times, was called 0 times"
 )]
 fn partial_eq_matcher_has_display_output_for_reference_when_printed_as_expected_call_with() {
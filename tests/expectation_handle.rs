@@ -0,0 +1,42 @@
+use mockiato::{mockable, ExpectedCalls};
+
+#[mockable]
+trait Worker {
+    fn process(&self, item: u32) -> bool;
+}
+
+#[test]
+fn handle_reports_call_count_while_mock_is_in_use() {
+    let mut worker = WorkerMock::new();
+    let handle = worker
+        .expect_process(|arg| arg.any())
+        .times(..=5)
+        .returns(true)
+        .handle();
+
+    for item in 0..3 {
+        assert_eq!(item, handle.call_count() as u32);
+        assert!(worker.process(item));
+    }
+
+    assert_eq!(3, handle.call_count());
+    assert!(handle.is_satisfied());
+    assert!(!handle.is_saturated());
+    assert_eq!(ExpectedCalls::from(..=5), handle.expected_calls());
+}
+
+#[test]
+fn cloned_handles_share_state() {
+    let mut worker = WorkerMock::new();
+    let handle = worker
+        .expect_process(|arg| arg.partial_eq(1))
+        .returns(false)
+        .handle();
+    let cloned_handle = handle.clone();
+
+    assert!(!cloned_handle.is_satisfied());
+    worker.process(1);
+
+    assert!(handle.is_saturated());
+    assert!(cloned_handle.is_saturated());
+}
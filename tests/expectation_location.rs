@@ -0,0 +1,56 @@
+use mockiato::mockable;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[mockable]
+trait MessageSender {
+    fn send(&self, message: &str);
+}
+
+#[test]
+fn verification_error_shows_where_the_expected_call_was_defined() {
+    let mut definition = 0;
+    let message = panic_message(|| {
+        let mut sender = MessageSenderMock::new();
+        definition = expect_hello(&mut sender);
+    });
+
+    assert!(message.contains(&defined_at(definition)), "{}", message);
+}
+
+/// Returns the line of the expected call
+fn expect_hello(sender: &mut MessageSenderMock<'_>) -> u32 {
+    sender.expect_send(|arg| arg.partial_eq("Hello")).times(1);
+    line!() - 1
+}
+
+#[test]
+fn call_error_shows_where_the_expected_call_was_defined() {
+    let mut sender = MessageSenderMock::new();
+    sender.expect_send(|arg| arg.partial_eq("Hello")).times(1);
+    let definition = line!() - 1;
+
+    sender.send("Hello");
+    let message = panic_message(|| sender.send("Hello"));
+
+    assert!(message.contains(&defined_at(definition)), "{}", message);
+
+    sender.disarm();
+}
+
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = catch_unwind(AssertUnwindSafe(f)).expect_err("Expected a panic");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .expect("Expected a formatted panic message")
+}
+
+#[cfg(rustc_has_track_caller)]
+fn defined_at(line: u32) -> String {
+    format!(" (defined at {}:{})", file!(), line)
+}
+
+#[cfg(not(rustc_has_track_caller))]
+fn defined_at(_line: u32) -> String {
+    String::new()
+}
@@ -0,0 +1,153 @@
+use mockiato::{mockable, Strictness};
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Default, PartialEq, Clone)]
+struct User {
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Item(u32);
+
+fn print_item(_item: &Item) {}
+
+#[mockable]
+trait Store {
+    fn get<T: Default + 'static>(&self, key: &str) -> T;
+
+    fn put<T>(&self, key: &str, value: T)
+    where
+        T: Debug + PartialEq + Clone + Send + Sync + 'static;
+
+    fn each<F: Fn(&Item) + 'static>(&self, f: F);
+}
+
+#[mockable(sync)]
+trait Queue: Send + Sync {
+    fn push<T: Debug + Send + 'static>(&self, value: T);
+}
+
+#[test]
+fn expectations_are_registered_per_type() {
+    let mut store = StoreMock::new();
+    store
+        .expect_get(|arg| arg.partial_eq("jane"))
+        .returns(User {
+            name: String::from("Jane"),
+        });
+    store
+        .expect_get::<u32, _>(|arg| arg.partial_eq("answer"))
+        .returns(42);
+
+    assert_eq!(42, store.get::<u32>("answer"));
+    assert_eq!("Jane", store.get::<User>("jane").name);
+}
+
+#[test]
+#[should_panic(expected = "The call generic_methods::StoreMock::get(\"answer\") was not expected.")]
+fn calls_with_other_type_arguments_are_not_expected() {
+    let mut store = StoreMock::new();
+    store
+        .expect_get(|arg| arg.partial_eq("answer"))
+        .returns(42_u32);
+
+    store.get::<u64>("answer");
+}
+
+#[test]
+fn generic_arguments_can_be_matched() {
+    let mut store = StoreMock::new();
+    store.expect_put(|arg| arg.partial_eq("answer"), |arg| arg.partial_eq(42_u32));
+    store.expect_put(|arg| arg.partial_eq("item"), |arg| arg.partial_eq(Item(1)));
+
+    store.put("item", Item(1));
+    store.put("answer", 42_u32);
+}
+
+#[test]
+fn closures_can_be_passed_to_generic_methods() {
+    let mut store = StoreMock::new();
+    store.expect_each::<fn(&Item), _>(|arg| arg.any()).times(2);
+
+    store.each((|_: &Item| {}) as fn(&Item));
+    store.each(print_item as fn(&Item));
+
+    assert_eq!(2, store.calls_to_each().len());
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for generic_methods::StoreMock::put were not")]
+fn unsatisfied_expectations_of_all_instantiations_are_verified() {
+    let mut store = StoreMock::new();
+    store.expect_put(|arg| arg.any(), |arg| arg.partial_eq(1_u8));
+    store.expect_put(|arg| arg.any(), |arg| arg.partial_eq(2_u16));
+
+    store.put("a", 1_u8);
+}
+
+#[test]
+fn strictness_applies_to_instantiations_created_later() {
+    let mut store = StoreMock::new();
+    store.set_strictness(Strictness::Nice);
+
+    assert_eq!(User::default(), store.get::<User>("jane"));
+    assert_eq!(0, store.get::<u32>("answer"));
+    assert_eq!(
+        vec![
+            String::from("generic_methods::StoreMock::get(\"jane\")"),
+            String::from("generic_methods::StoreMock::get(\"answer\")"),
+        ],
+        store.unexpected_calls()
+    );
+}
+
+#[test]
+fn calls_to_generic_methods_can_be_verified() {
+    let mut store = StoreMock::new();
+    store.set_strictness(Strictness::Nice);
+
+    store.put("a", 1_u32);
+    store.put("b", 2_u32);
+    store.put("c", String::from("c"));
+
+    store
+        .verify_put::<u32, _, _>(|arg| arg.any(), |arg| arg.any())
+        .times(2);
+    store.verify_put::<String, _, _>(|arg| arg.partial_eq("c"), |arg| arg.any());
+    assert_eq!(3, store.calls_to_put().len());
+}
+
+#[test]
+fn mocks_with_generic_methods_can_be_cloned() {
+    let mut store = StoreMock::new();
+    store.expect_get(|arg| arg.any()).returns(1_u32).times(2);
+
+    let clone = store.clone();
+
+    assert_eq!(1, store.get::<u32>("a"));
+    assert_eq!(1, store.get::<u32>("b"));
+    assert_eq!(1, clone.get::<u32>("a"));
+    assert_eq!(1, clone.get::<u32>("b"));
+}
+
+#[test]
+fn generic_methods_of_sync_mocks_can_be_called_from_other_threads() {
+    let mut queue = QueueMock::new();
+    queue.expect_push::<String, _>(|arg| arg.partial_eq(String::from("message")));
+    queue.expect_push::<u32, _>(|arg| arg.partial_eq(7));
+    let queue = Arc::new(queue);
+
+    let handle = thread::spawn({
+        let queue = queue.clone();
+        move || {
+            queue.push(7_u32);
+            queue.push(String::from("message"));
+        }
+    });
+
+    queue.wait_for_push::<String>(1, Duration::from_secs(10));
+    handle.join().unwrap();
+}
@@ -0,0 +1,171 @@
+use mockiato::{mockable, Grammar, Rule, Sequence};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[mockable]
+trait Connection {
+    fn connect(&self);
+    fn send(&self, message: &str);
+    fn ping(&self);
+    fn disconnect(&self);
+}
+
+fn connection_with_grammar() -> (ConnectionMock<'static>, Grammar) {
+    let mut connection = ConnectionMock::new();
+    let connect = connection.expect_connect().handle();
+    let send = connection.expect_send(|arg| arg.any()).handle();
+    let ping = connection.expect_ping().handle();
+    let disconnect = connection.expect_disconnect().handle();
+
+    let grammar = Grammar::new(
+        Rule::call(&connect)
+            .then(Rule::call(&send).or(Rule::call(&ping)).repeat(..))
+            .then(Rule::call(&disconnect).optional()),
+    );
+
+    (connection, grammar)
+}
+
+#[test]
+fn calls_matching_grammar_are_accepted() {
+    let (connection, _grammar) = connection_with_grammar();
+
+    connection.connect();
+    connection.send("foo");
+    connection.ping();
+    connection.send("bar");
+    connection.disconnect();
+}
+
+#[test]
+fn optional_and_repeated_rules_can_be_skipped() {
+    let (connection, _grammar) = connection_with_grammar();
+
+    connection.connect();
+}
+
+#[test]
+fn grammar_can_span_multiple_mocks() {
+    let mut first = ConnectionMock::new();
+    let mut second = ConnectionMock::new();
+    let first_connect = first.expect_connect().handle();
+    let second_connect = second.expect_connect().handle();
+
+    let _grammar = Grammar::new(
+        Rule::call(&first_connect)
+            .then(Rule::call(&second_connect))
+            .repeat(2),
+    );
+
+    first.connect();
+    second.connect();
+    first.connect();
+    second.connect();
+}
+
+#[test]
+#[should_panic(
+    expected = "The call grammar::ConnectionMock::send(\"foo\") does not match the \
+                grammar.\nGrammar: connect() (send(*) | ping())* disconnect()?\nThe calls \
+                diverged from the grammar at position 3.\nCalls matching the grammar:\n1. \
+                connect()\n2. disconnect()\nExpected one of:\nno further calls\n"
+)]
+fn call_diverging_from_grammar_panics() {
+    let (connection, _grammar) = connection_with_grammar();
+
+    connection.connect();
+    connection.disconnect();
+    connection.send("foo");
+}
+
+#[test]
+#[should_panic(expected = "Expected one of:\nconnect()\n")]
+fn call_before_first_rule_panics() {
+    let (connection, _grammar) = connection_with_grammar();
+
+    connection.ping();
+}
+
+#[test]
+#[should_panic(
+    expected = "The calls did not complete the grammar.\nGrammar: connect() disconnect()\nThe \
+                calls diverged from the grammar at position 2."
+)]
+fn incomplete_grammar_panics_when_mock_is_dropped() {
+    let mut connection = ConnectionMock::new();
+    let connect = connection.expect_connect().handle();
+    let disconnect = connection.expect_disconnect().handle();
+
+    let _grammar = Grammar::new(Rule::call(&connect).then(Rule::call(&disconnect)));
+
+    connection.connect();
+}
+
+#[test]
+fn incomplete_grammar_is_reported_by_try_verify() {
+    let mut connection = ConnectionMock::new();
+    let connect = connection.expect_connect().handle();
+    let disconnect = connection.expect_disconnect().handle();
+
+    let _grammar = Grammar::new(Rule::call(&connect).then(Rule::call(&disconnect)));
+
+    connection.connect();
+
+    let report = connection.try_verify().unwrap_err();
+    assert_eq!(1, report.failures().len());
+    assert_eq!(1, report.failures()[0].incomplete_grammars().len());
+
+    connection.disconnect();
+    assert!(connection.try_verify().is_ok());
+}
+
+#[test]
+fn disarmed_mock_does_not_verify_grammar() {
+    let mut connection = ConnectionMock::new();
+    let connect = connection.expect_connect().handle();
+    let disconnect = connection.expect_disconnect().handle();
+
+    let grammar = Grammar::new(Rule::call(&connect).then(Rule::call(&disconnect)));
+
+    connection.connect();
+    connection.disarm();
+    drop(grammar);
+}
+
+#[test]
+#[should_panic(
+    expected = "cannot be part of a grammar, because its number of calls was set using \
+                `times(..)`."
+)]
+fn grammar_rejects_expected_call_with_times() {
+    let mut connection = ConnectionMock::new();
+    let connect = connection.expect_connect().times(2).handle();
+
+    let _grammar = Grammar::new(Rule::call(&connect));
+}
+
+#[test]
+#[should_panic(expected = "cannot be set using `times(..)`, because it is part of a grammar.")]
+fn times_panics_for_expected_call_in_grammar() {
+    let mut connection = ConnectionMock::new();
+    let mut connect = connection.expect_connect();
+
+    let _grammar = Grammar::new(Rule::call(&connect.handle()));
+
+    connect.times(2);
+}
+
+#[test]
+fn call_rejected_by_sequence_does_not_advance_grammar() {
+    let sequence = Sequence::new();
+    let mut connection = ConnectionMock::new();
+    connection.expect_ping().in_sequence(&sequence);
+    let connect = connection.expect_connect().in_sequence(&sequence).handle();
+
+    let _grammar = Grammar::new(Rule::call(&connect));
+
+    let result = catch_unwind(AssertUnwindSafe(|| connection.connect()));
+    assert!(result.is_err());
+
+    connection.ping();
+    connection.connect();
+}
@@ -0,0 +1,55 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+}
+
+#[test]
+#[should_panic(expected = "of missing_return_value::GreeterMock::greet has no return value")]
+fn panics_when_expected_call_has_no_return_value() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_greet(|arg| arg.partial_eq("Paul")).times(1);
+}
+
+#[test]
+#[should_panic(expected = "of missing_return_value::GreeterMock::greet has no return value")]
+fn panics_when_stub_has_no_return_value() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.allow_greet(|arg| arg.any());
+}
+
+#[test]
+fn does_not_panic_when_expected_call_panics() {
+    let mut greeter = GreeterMock::new();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Paul"))
+        .times(0)
+        .panics();
+}
+
+#[test]
+fn does_not_panic_when_expected_call_without_return_value_is_never_called() {
+    let mut greeter = GreeterMock::new();
+
+    greeter.expect_greet(|arg| arg.partial_eq("Paul")).times(0);
+}
+
+#[test]
+fn handle_of_expected_call_without_return_value_can_be_used_as_prerequisite() {
+    let mut greeter = GreeterMock::new();
+    let handle = greeter
+        .expect_greet(|arg| arg.partial_eq("Paul"))
+        .times(0)
+        .handle();
+
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Peter"))
+        .after(&handle)
+        .returns(String::from("Hello Peter"));
+
+    assert_eq!("Hello Peter", greeter.greet("Peter"));
+}
@@ -0,0 +1,86 @@
+use mockiato::{mockable, Strictness};
+
+struct Secret;
+
+#[mockable(nice)]
+trait Logger {
+    fn log(&self, message: &str);
+
+    fn level(&self) -> u8;
+}
+
+#[mockable]
+trait Metrics {
+    fn increment(&self, name: &str) -> usize;
+
+    fn secret(&self) -> Secret;
+}
+
+#[test]
+fn unexpected_calls_return_default_value() {
+    let logger = LoggerMock::new();
+
+    logger.log("foo");
+
+    assert_eq!(0, logger.level());
+}
+
+#[test]
+fn expected_calls_are_still_used() {
+    let mut logger = LoggerMock::new();
+    logger.expect_level().returns(3);
+
+    assert_eq!(3, logger.level());
+}
+
+#[test]
+fn unexpected_calls_are_recorded_in_order() {
+    let logger = LoggerMock::new();
+
+    logger.log("foo");
+    logger.level();
+    logger.log("bar");
+
+    assert_eq!(
+        vec![
+            "nice::LoggerMock::log(\"foo\")",
+            "nice::LoggerMock::level()",
+            "nice::LoggerMock::log(\"bar\")",
+        ],
+        logger.unexpected_calls()
+    );
+}
+
+#[test]
+#[should_panic(expected = "The expected calls for nice::LoggerMock::level were not satisified.")]
+fn missing_expected_calls_still_panic() {
+    let mut logger = LoggerMock::new();
+    logger.expect_level().returns(3);
+}
+
+#[test]
+fn strictness_can_be_set_at_runtime() {
+    let mut metrics = MetricsMock::new();
+    metrics.set_strictness(Strictness::Nice);
+
+    assert_eq!(0, metrics.increment("requests"));
+    assert_eq!(1, metrics.unexpected_calls().len());
+}
+
+#[test]
+#[should_panic(expected = "The call nice::LoggerMock::log(\"foo\") was not expected.")]
+fn nice_mock_can_be_made_strict() {
+    let mut logger = LoggerMock::new();
+    logger.set_strictness(Strictness::Strict);
+
+    logger.log("foo");
+}
+
+#[test]
+#[should_panic(expected = "The call nice::MetricsMock::secret() was not expected.")]
+fn unexpected_calls_without_default_return_value_panic() {
+    let mut metrics = MetricsMock::new();
+    metrics.set_strictness(Strictness::Nice);
+
+    metrics.secret();
+}
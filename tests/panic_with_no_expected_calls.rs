@@ -7,23 +7,22 @@ trait Greeter {
     fn greet_unknown_person(&self) -> String;
 }
 
-#[cfg(rustc_is_nightly)]
 #[test]
-#[should_panic(
-    expected = "The call GreeterMock::greet_unknown_person() was not expected.\nNo calls to \
-                GreeterMock::greet_unknown_person were expected."
-)]
+#[should_panic(expected = "The call panic_with_no_expected_calls::GreeterMock::\
+                           greet_unknown_person() was not expected.\nNo calls to \
+                           panic_with_no_expected_calls::GreeterMock::greet_unknown_person were \
+                           expected.")]
 fn panics_with_no_expected_calls_with_no_arguments() {
     let greeter = GreeterMock::new();
 
     greeter.greet_unknown_person();
 }
 
-#[cfg(rustc_is_nightly)]
 #[test]
 #[should_panic(
-    expected = "The call GreeterMock::greet(\"John\") was not expected.\nNo calls to \
-                GreeterMock::greet were expected."
+    expected = "The call panic_with_no_expected_calls::GreeterMock::greet(\"John\") was not \
+                expected.\nNo calls to panic_with_no_expected_calls::GreeterMock::greet were \
+                expected."
 )]
 fn panics_with_no_expected_calls_with_one_argument() {
     let greeter = GreeterMock::new();
@@ -31,11 +30,11 @@ fn panics_with_no_expected_calls_with_one_argument() {
     greeter.greet("John");
 }
 
-#[cfg(rustc_is_nightly)]
 #[test]
 #[should_panic(
-    expected = "The call GreeterMock::greet_two_people(\"John\", \"Adam\") was not expected.\nNo \
-                calls to GreeterMock::greet_two_people were expected."
+    expected = "The call panic_with_no_expected_calls::GreeterMock::greet_two_people(\"John\", \
+                \"Adam\") was not expected.\nNo calls to \
+                panic_with_no_expected_calls::GreeterMock::greet_two_people were expected."
 )]
 fn panics_with_no_expected_calls_with_two_argument() {
     let greeter = GreeterMock::new();
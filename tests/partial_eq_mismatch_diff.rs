@@ -0,0 +1,38 @@
+use mockiato::mockable;
+
+#[derive(Debug, PartialEq)]
+struct Person {
+    name: String,
+    age: u8,
+}
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, person: &Person) -> String;
+}
+
+// The changed lines are colored depending on `MOCKIATO_COLOR` and whether stderr is a TTY.
+// Their rendering is covered by the unit tests of the diff.
+// The values expected by argument matchers are only printed on nightly rust.
+#[cfg(rustc_is_nightly)]
+#[test]
+#[should_panic(expected = "Differences to greet(Person { name: \"Name\", age: 30 }):
+Argument `person`:
+  Person {
+      name: \"Name\",
+")]
+fn unexpected_call_shows_diff_to_expected_call() {
+    let expected_person = Person {
+        name: String::from("Name"),
+        age: 30,
+    };
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq(&expected_person))
+        .returns(String::from("Hello Name"));
+
+    greeter.greet(&Person {
+        name: String::from("Name"),
+        age: 31,
+    });
+}
@@ -0,0 +1,82 @@
+use mockiato::mockable;
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+    fn wave(&self, times: u32);
+}
+
+#[test]
+fn calls_are_recorded_with_arguments() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .allow_greet(|arg| arg.any())
+        .returns(String::from("Hello"));
+
+    greeter.greet("Jane");
+    greeter.greet("Paul");
+
+    let calls = greeter.calls_to_greet();
+    assert_eq!(2, calls.len());
+    assert_eq!("recorded_calls::GreeterMock::greet", calls[0].method_name());
+    assert_eq!(["\"Jane\""], calls[0].arguments());
+    assert_eq!(["\"Paul\""], calls[1].arguments());
+    assert_eq!(
+        "recorded_calls::GreeterMock::greet(\"Paul\")",
+        calls[1].to_string()
+    );
+}
+
+#[test]
+fn calls_record_the_matched_expectation() {
+    let mut greeter = GreeterMock::new();
+    greeter.expect_wave(|arg| arg.any());
+
+    greeter.wave(3);
+
+    let calls = greeter.calls_to_wave();
+    assert_eq!(Some("wave(*)"), calls[0].matched_expectation());
+}
+
+#[test]
+fn recorded_calls_are_ordered_across_methods() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .allow_greet(|arg| arg.any())
+        .returns(String::from("Hello"));
+    greeter.allow_wave(|arg| arg.any());
+
+    greeter.wave(1);
+    greeter.greet("Jane");
+    greeter.wave(2);
+
+    let calls: Vec<_> = greeter
+        .recorded_calls()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        vec![
+            "recorded_calls::GreeterMock::wave(1)",
+            "recorded_calls::GreeterMock::greet(\"Jane\")",
+            "recorded_calls::GreeterMock::wave(2)",
+        ],
+        calls
+    );
+    assert!(
+        greeter.calls_to_wave()[0].sequence_number()
+            < greeter.calls_to_greet()[0].sequence_number()
+    );
+}
+
+#[test]
+fn recorded_calls_are_snapshots() {
+    let mut greeter = GreeterMock::new();
+    greeter.allow_wave(|arg| arg.any());
+
+    let calls = greeter.calls_to_wave();
+    greeter.wave(1);
+
+    assert!(calls.is_empty());
+    assert_eq!(1, greeter.calls_to_wave().len());
+}
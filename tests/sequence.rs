@@ -0,0 +1,119 @@
+use mockiato::{mockable, Sequence};
+
+#[mockable]
+trait Connection {
+    fn open(&self);
+    fn write(&self, data: &str) -> usize;
+    fn close(&self);
+}
+
+#[mockable]
+trait Logger {
+    fn log(&self, message: &str);
+}
+
+#[test]
+fn calls_in_sequence_work() {
+    let sequence = Sequence::new();
+    let mut connection = ConnectionMock::new();
+
+    connection.expect_open().in_sequence(&sequence);
+    connection
+        .expect_write(|arg| arg.any())
+        .times(3)
+        .returns(1)
+        .in_sequence(&sequence);
+    connection.expect_close().in_sequence(&sequence);
+
+    connection.open();
+    connection.write("foo");
+    connection.write("bar");
+    connection.write("baz");
+    connection.close();
+}
+
+#[test]
+fn sequences_can_be_shared_between_mocks() {
+    let sequence = Sequence::new();
+    let mut connection = ConnectionMock::new();
+    let mut logger = LoggerMock::new();
+
+    connection.expect_open().in_sequence(&sequence);
+    logger
+        .expect_log(|arg| arg.partial_eq("opened"))
+        .in_sequence(&sequence);
+    connection.expect_close().in_sequence(&sequence);
+
+    connection.open();
+    logger.log("opened");
+    connection.close();
+}
+
+#[test]
+#[should_panic(
+    expected = "The call sequence::ConnectionMock::close() was made out of sequence.\nThe \
+                following call was expected next:\nwrite(*) exactly 3 times, was called 1 time"
+)]
+fn call_before_previous_calls_are_satisfied_panics() {
+    let sequence = Sequence::new();
+    let mut connection = ConnectionMock::new();
+
+    connection.expect_open().in_sequence(&sequence);
+    connection
+        .expect_write(|arg| arg.any())
+        .times(3)
+        .returns(1)
+        .in_sequence(&sequence);
+    connection.expect_close().in_sequence(&sequence);
+
+    connection.open();
+    connection.write("foo");
+    connection.close();
+}
+
+#[test]
+#[should_panic(expected = "The call sequence::ConnectionMock::open() was made out of sequence.")]
+fn call_after_later_call_panics() {
+    let sequence = Sequence::new();
+    let mut connection = ConnectionMock::new();
+    let mut logger = LoggerMock::new();
+
+    connection.expect_open().times(..).in_sequence(&sequence);
+    logger.expect_log(|arg| arg.any()).in_sequence(&sequence);
+
+    connection.open();
+    logger.log("opened");
+    connection.open();
+}
+
+#[test]
+fn expected_call_can_be_moved_to_another_sequence() {
+    let first_sequence = Sequence::new();
+    let second_sequence = Sequence::new();
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_open()
+        .in_sequence(&first_sequence)
+        .in_sequence(&second_sequence);
+    connection.expect_close().in_sequence(&first_sequence);
+
+    connection.close();
+    connection.open();
+}
+
+#[test]
+#[should_panic(expected = "The call sequence::ConnectionMock::open() was made out of sequence.")]
+fn moved_expected_call_is_ordered_in_the_other_sequence() {
+    let first_sequence = Sequence::new();
+    let second_sequence = Sequence::new();
+    let mut connection = ConnectionMock::new();
+
+    connection.expect_close().in_sequence(&second_sequence);
+    connection
+        .expect_open()
+        .in_sequence(&first_sequence)
+        .in_sequence(&second_sequence);
+
+    connection.open();
+}
@@ -0,0 +1,84 @@
+use mockiato::{mockable, States};
+
+#[mockable]
+trait Connection {
+    fn open(&self);
+    fn read(&self) -> Option<String>;
+    fn close(&self);
+}
+
+#[test]
+fn expected_calls_with_same_arguments_can_be_defined_for_different_states() {
+    let connection_state = States::new("connection", "closed");
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_open()
+        .then_state(connection_state.to("open"));
+    connection
+        .expect_read()
+        .times(2)
+        .when(connection_state.is("open"))
+        .returns(Some(String::from("data")));
+    connection
+        .expect_read()
+        .when(connection_state.is("closed"))
+        .returns(None);
+
+    assert_eq!(None, connection.read());
+    connection.open();
+    assert_eq!(Some(String::from("data")), connection.read());
+    assert_eq!(Some(String::from("data")), connection.read());
+    assert_eq!("open", connection_state.current());
+}
+
+#[test]
+fn state_machine_can_be_shared_between_mocks() {
+    let connection_state = States::new("connection", "closed");
+    let mut first = ConnectionMock::new();
+    let mut second = ConnectionMock::new();
+
+    first.expect_open().then_state(connection_state.to("open"));
+    second
+        .expect_close()
+        .when(connection_state.is("open"))
+        .then_state(connection_state.to("closed"));
+
+    first.open();
+    second.close();
+    assert_eq!("closed", connection_state.current());
+}
+
+#[test]
+fn state_guards_work_with_calls_in_order() {
+    let connection_state = States::new("connection", "closed");
+    let mut connection = ConnectionMock::new();
+
+    connection.expect_read_calls_in_order();
+    connection
+        .expect_read()
+        .when(connection_state.is("closed"))
+        .returns(None);
+
+    assert_eq!(None, connection.read());
+}
+
+#[test]
+#[should_panic(
+    expected = "The call states::ConnectionMock::read() was not expected in the current \
+                state.\nCurrent state:\nconnection is closed\nThe following calls match, but are \
+                expected in a different state:\nread() when connection is open -> Some(\"data\") \
+                then connection is closed exactly 1 time, was called 0 times"
+)]
+fn call_in_unexpected_state_panics() {
+    let connection_state = States::new("connection", "closed");
+    let mut connection = ConnectionMock::new();
+
+    connection
+        .expect_read()
+        .when(connection_state.is("open"))
+        .then_state(connection_state.to("closed"))
+        .returns(Some(String::from("data")));
+
+    connection.read();
+}
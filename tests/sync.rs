@@ -0,0 +1,118 @@
+use mockiato::{mockable, Strictness};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+#[mockable(sync)]
+trait Greeter: Send + Sync {
+    fn greet(&self, name: &str) -> String;
+}
+
+#[mockable(sync)]
+trait Counter {
+    fn increment(&self, amount: u32);
+}
+
+fn assert_send_sync<T>(_: &T)
+where
+    T: Send + Sync,
+{
+}
+
+#[test]
+fn sync_mocks_are_send_and_sync() {
+    let greeter = GreeterMock::new();
+    assert_send_sync(&greeter);
+
+    let counter = CounterMock::new();
+    assert_send_sync(&counter);
+}
+
+#[test]
+fn sync_mocks_can_be_shared_between_threads() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.partial_eq("Jane"))
+        .times(4)
+        .returns(String::from("Hello Jane"));
+
+    let greeter: Arc<dyn Greeter + Send + Sync> = Arc::new(greeter);
+
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let greeter = greeter.clone();
+            thread::spawn(move || greeter.greet("Jane"))
+        })
+        .collect();
+
+    for thread in threads {
+        assert_eq!("Hello Jane", thread.join().unwrap());
+    }
+}
+
+#[test]
+fn calls_from_multiple_threads_are_counted() {
+    let mut counter = CounterMock::new();
+    let increment = counter.expect_increment(|arg| arg.any()).times(8).handle();
+
+    let counter = Arc::new(counter);
+
+    let threads: Vec<_> = (0..8)
+        .map(|amount| {
+            let counter = counter.clone();
+            thread::spawn(move || counter.increment(amount))
+        })
+        .collect();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    assert_eq!(8, increment.call_count());
+    counter.verify_increment(|arg| arg.partial_eq(3));
+}
+
+#[test]
+fn saturated_expected_calls_accept_no_concurrent_calls() {
+    const THREADS: usize = 16;
+
+    for _ in 0..50 {
+        let mut counter = CounterMock::new();
+        counter.set_strictness(Strictness::Nice);
+        let increment = counter.expect_increment(|arg| arg.any()).times(1).handle();
+
+        let counter = Arc::new(counter);
+        let barrier = Arc::new(Barrier::new(THREADS));
+
+        let threads: Vec<_> = (0..THREADS)
+            .map(|amount| {
+                let counter = counter.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    counter.increment(amount as u32);
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(1, increment.call_count());
+        assert_eq!(THREADS - 1, counter.unexpected_calls().len());
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "The expected calls for sync::CounterMock::increment were not satisified."
+)]
+fn sync_mocks_are_verified_when_dropped() {
+    let mut counter = CounterMock::new();
+    counter.expect_increment(|arg| arg.any());
+
+    let counter = Arc::new(counter);
+    let counter_clone = counter.clone();
+    thread::spawn(move || drop(counter_clone)).join().unwrap();
+    drop(counter);
+}
@@ -0,0 +1,77 @@
+use mockiato::{mockable, ExpectedCalls};
+
+#[mockable]
+trait Greeter {
+    fn greet(&self, name: &str) -> String;
+    fn wave(&self);
+}
+
+#[test]
+fn try_verify_returns_ok_when_expected_calls_were_made() {
+    let mut greeter = GreeterMock::new();
+    greeter.expect_wave();
+
+    greeter.wave();
+
+    assert!(greeter.try_verify().is_ok());
+}
+
+#[test]
+fn try_verify_reports_every_failing_method() {
+    let mut greeter = GreeterMock::new();
+    greeter
+        .expect_greet(|arg| arg.any())
+        .returns(String::from("Hello"));
+    greeter.expect_wave().times(2);
+
+    greeter.wave();
+
+    let report = greeter.try_verify().unwrap_err();
+    let method_names: Vec<_> = report
+        .failures()
+        .iter()
+        .map(|failure| failure.method_name())
+        .collect();
+    assert_eq!(
+        vec![
+            "try_verify::GreeterMock::greet",
+            "try_verify::GreeterMock::wave"
+        ],
+        method_names
+    );
+    assert!(report
+        .to_string()
+        .contains("wave() -> () exactly 2 times, was called 1 time"));
+
+    let wave = &report.failures()[1].expected_calls()[0];
+    assert_eq!("wave() -> ()", wave.description());
+    assert_eq!(&ExpectedCalls::from(2), wave.expected_calls());
+    assert_eq!(1, wave.call_count());
+    assert!(!wave.is_satisfied());
+
+    greeter.disarm();
+}
+
+#[test]
+fn reports_of_multiple_mocks_can_be_merged() {
+    let mut first = GreeterMock::new();
+    let mut second = GreeterMock::new();
+    first.expect_wave();
+    second.expect_wave();
+
+    let mut report = first.try_verify().unwrap_err();
+    report.merge(second.try_verify().unwrap_err());
+
+    assert_eq!(2, report.failures().len());
+
+    first.disarm();
+    second.disarm();
+}
+
+#[test]
+fn disarmed_mock_is_not_verified_when_dropped() {
+    let mut greeter = GreeterMock::new();
+    greeter.expect_wave();
+
+    greeter.disarm();
+}
@@ -0,0 +1,16 @@
+use mockiato::mockable;
+
+#[mockable]
+trait MessageSender {
+    fn send_message(&self, message: &str);
+}
+
+#[test]
+fn unit_return_value_does_not_need_to_be_specified() {
+    let mut sender = MessageSenderMock::new();
+    sender
+        .expect_send_message(|arg| arg.partial_eq("Hello"))
+        .times(1);
+
+    sender.send_message("Hello");
+}
@@ -0,0 +1,177 @@
+use mockiato::{mockable, Strictness};
+use std::fmt::Debug;
+use std::rc::Rc;
+
+#[mockable]
+trait MessageSender {
+    fn send_message(&self, recipient: &str, message: &str);
+}
+
+#[mockable]
+trait Counter {
+    fn add(&self, amount: u32, tags: Vec<String>) -> u32;
+}
+
+#[derive(Debug, PartialEq)]
+struct NotClone(u32);
+
+#[mockable]
+trait Consumer {
+    fn consume(&self, value: NotClone);
+}
+
+#[mockable]
+trait SharedConsumer {
+    fn consume(&self, value: Rc<u32>);
+}
+
+#[mockable]
+trait GenericConsumer<T>
+where
+    T: Debug,
+{
+    fn consume(&self, value: T);
+}
+
+fn nice_message_sender() -> MessageSenderMock<'static> {
+    let mut message_sender = MessageSenderMock::new();
+    message_sender.set_strictness(Strictness::Nice);
+    message_sender
+}
+
+#[test]
+fn verify_succeeds_if_call_was_made_once() {
+    let message_sender = nice_message_sender();
+
+    message_sender.send_message("Paul", "Hello Paul");
+
+    message_sender.verify_send_message(|arg| arg.partial_eq("Paul"), |arg| arg.any());
+}
+
+#[test]
+fn verify_checks_number_of_matching_calls() {
+    let message_sender = nice_message_sender();
+
+    message_sender.send_message("Paul", "Hello Paul");
+    message_sender.send_message("Jane", "Hello Jane");
+    message_sender.send_message("Paul", "Goodbye Paul");
+
+    message_sender
+        .verify_send_message(|arg| arg.partial_eq("Paul"), |arg| arg.any())
+        .times(2);
+    message_sender
+        .verify_send_message(|arg| arg.any(), |arg| arg.any())
+        .times(2..);
+    message_sender
+        .verify_send_message(|arg| arg.partial_eq("Peter"), |arg| arg.any())
+        .times(0);
+}
+
+#[test]
+fn verify_includes_calls_matching_expectations_and_stubs() {
+    let mut counter = CounterMock::new();
+    counter
+        .expect_add(|arg| arg.partial_eq(1), |arg| arg.any())
+        .returns(1);
+    counter
+        .allow_add(|arg| arg.any(), |arg| arg.any())
+        .returns(0);
+
+    counter.add(1, vec![String::from("first")]);
+    counter.add(2, vec![String::from("second")]);
+
+    counter
+        .verify_add(
+            |arg| arg.any(),
+            |arg| arg.unordered_vec_eq(vec![String::from("second")]),
+        )
+        .times(1);
+    counter
+        .verify_add(|arg| arg.any(), |arg| arg.any())
+        .times(2);
+}
+
+#[test]
+#[should_panic(expected = "was expected to be made exactly 1 time, but was made 2 times.")]
+fn verify_panics_if_call_was_made_too_often() {
+    let message_sender = nice_message_sender();
+
+    message_sender.send_message("Paul", "Hello Paul");
+    message_sender.send_message("Paul", "Hello Paul");
+
+    message_sender.verify_send_message(|arg| arg.partial_eq("Paul"), |arg| arg.any());
+}
+
+#[test]
+#[should_panic(expected = "was expected to be made exactly 1 time, but was made 0 times.")]
+fn verify_without_times_is_checked_when_dropped() {
+    let message_sender = nice_message_sender();
+
+    message_sender.send_message("Paul", "Hello Paul");
+
+    let verification =
+        message_sender.verify_send_message(|arg| arg.partial_eq("Jane"), |arg| arg.any());
+    drop(verification);
+}
+
+#[test]
+#[should_panic(
+    expected = "No calls to verify_recorded_calls::MessageSenderMock::send_message were recorded."
+)]
+fn verify_panics_if_no_calls_were_made() {
+    let message_sender = nice_message_sender();
+
+    message_sender
+        .verify_send_message(|arg| arg.any(), |arg| arg.any())
+        .times(1..);
+}
+
+#[test]
+#[should_panic(expected = "The following recorded calls came closest to \
+                           matching:\nverify_recorded_calls::CounterMock::add(2, [\"a\"])\n")]
+fn verify_lists_closest_calls() {
+    let mut counter = CounterMock::new();
+    counter.set_strictness(Strictness::Nice);
+
+    counter.add(1, vec![String::from("c")]);
+    counter.add(2, vec![String::from("a")]);
+    counter.add(3, vec![String::from("c")]);
+
+    counter.verify_add(
+        |arg| arg.partial_eq(2),
+        |arg| arg.partial_eq(vec![String::from("b")]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "because the arguments of the following calls were not \
+                           recorded:\nverify_recorded_calls::ConsumerMock::consume(NotClone(1))\n")]
+fn verify_panics_if_arguments_were_not_recorded() {
+    let mut consumer = ConsumerMock::new();
+    consumer.set_strictness(Strictness::Nice);
+
+    consumer.consume(NotClone(1));
+
+    consumer.verify_consume(|arg| arg.partial_eq(NotClone(1)));
+}
+
+#[test]
+#[should_panic(expected = "because the arguments of the following calls were not recorded:")]
+fn verify_panics_if_arguments_have_generic_types() {
+    let mut consumer = GenericConsumerMock::<u32>::new();
+    consumer.set_strictness(Strictness::Nice);
+
+    consumer.consume(1);
+
+    consumer.verify_consume(|arg| arg.partial_eq(1));
+}
+
+#[test]
+fn verify_supports_arguments_that_are_not_send_on_mocks_that_are_not_sync() {
+    let mut consumer = SharedConsumerMock::new();
+    consumer.set_strictness(Strictness::Nice);
+
+    consumer.consume(Rc::new(1));
+
+    consumer.verify_consume(|arg| arg.partial_eq(Rc::new(1)));
+}
@@ -0,0 +1,132 @@
+use mockiato::mockable;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[mockable(sync)]
+trait Worker: Send + Sync {
+    fn process(&self, job: u32);
+
+    fn finish(&self);
+}
+
+#[test]
+fn wait_for_returns_once_calls_were_made_on_another_thread() {
+    let mut worker = WorkerMock::new();
+    worker.expect_process(|arg| arg.any()).times(3);
+    let worker = Arc::new(worker);
+
+    let handle = thread::spawn({
+        let worker = worker.clone();
+        move || {
+            for job in 0..3 {
+                worker.process(job);
+            }
+        }
+    });
+
+    worker.wait_for_process(3, TIMEOUT);
+    assert_eq!(3, worker.calls_to_process().len());
+
+    handle.join().unwrap();
+}
+
+#[test]
+fn wait_for_is_notified_when_return_value_panics() {
+    let mut worker = WorkerMock::new();
+    worker.expect_process(|arg| arg.any()).panics();
+    let worker = Arc::new(worker);
+    let start = Instant::now();
+
+    let handle = thread::spawn({
+        let worker = worker.clone();
+        move || worker.process(1)
+    });
+
+    worker.wait_for_process(1, TIMEOUT);
+    assert!(start.elapsed() < TIMEOUT);
+
+    assert!(handle.join().is_err());
+}
+
+#[test]
+fn wait_for_returns_immediately_if_calls_were_already_made() {
+    let mut worker = WorkerMock::new();
+    worker.expect_finish();
+
+    worker.finish();
+
+    worker.wait_for_finish(1, Duration::from_millis(0));
+}
+
+#[test]
+#[should_panic(
+    expected = "waiting for wait_for_calls::WorkerMock::process to be called at least 2 \
+                times.\nThe following calls were made:\nwait_for_calls::WorkerMock::process(7)"
+)]
+fn wait_for_lists_calls_made_so_far_on_timeout() {
+    let mut worker = WorkerMock::new();
+    worker.expect_process(|arg| arg.any()).times(2);
+
+    worker.process(7);
+
+    worker.wait_for_process(2, Duration::from_millis(10));
+}
+
+#[test]
+#[should_panic(expected = "No calls to wait_for_calls::WorkerMock::finish were made.")]
+fn wait_for_panics_if_no_calls_were_made() {
+    let mut worker = WorkerMock::new();
+    worker.expect_finish();
+
+    worker.wait_for_finish(1, Duration::from_millis(10));
+}
+
+#[test]
+fn wait_satisfied_returns_once_expected_call_is_satisfied() {
+    let mut worker = WorkerMock::new();
+    let finish = worker.expect_finish().handle();
+    let worker = Arc::new(worker);
+
+    let handle = thread::spawn({
+        let worker = worker.clone();
+        move || worker.finish()
+    });
+
+    finish.wait_satisfied(TIMEOUT);
+    assert_eq!(1, finish.call_count());
+
+    handle.join().unwrap();
+}
+
+#[test]
+fn wait_satisfied_is_notified_when_return_value_panics() {
+    let mut worker = WorkerMock::new();
+    let finish = worker.expect_finish().panics().handle();
+    let worker = Arc::new(worker);
+    let start = Instant::now();
+
+    let handle = thread::spawn({
+        let worker = worker.clone();
+        move || worker.finish()
+    });
+
+    finish.wait_satisfied(TIMEOUT);
+    assert!(start.elapsed() < TIMEOUT);
+
+    assert!(handle.join().is_err());
+}
+
+#[test]
+#[should_panic(expected = "Timed out after 10ms waiting for the expected call to be \
+                           satisfied:\nfinish() exactly 2 times, was called 1 time")]
+fn wait_satisfied_panics_on_timeout() {
+    let mut worker = WorkerMock::new();
+    let finish = worker.expect_finish().times(2).handle();
+
+    worker.finish();
+
+    finish.wait_satisfied(Duration::from_millis(10));
+}